@@ -110,6 +110,10 @@ pub use bytes::{Bytes, BytesMut};
 #[cfg(not(feature = "bytes_backend"))]
 pub use buffer::{BufMut, Bytes, BytesMut};
 
+// Re-export bitflags so `#[bebytes(bitflags(..))]` fields resolve without a direct dependency
+#[cfg(feature = "bitflags")]
+pub use bitflags;
+
 /// Error type for `BeBytes` operations
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BeBytesError {
@@ -131,6 +135,13 @@ pub enum BeBytesError {
         max: u128,
         field: &'static str,
     },
+    /// A signed bit field value outside the two's complement range of its bits, which
+    /// runs from `-max - 1` to `max`
+    InvalidSignedBitField {
+        value: i128,
+        max: i128,
+        field: &'static str,
+    },
     InvalidUtf8 {
         field: &'static str,
     },
@@ -141,6 +152,15 @@ pub enum BeBytesError {
     InvalidChar {
         value: u32,
     },
+    InvalidVariantName {
+        type_name: &'static str,
+    },
+    /// A `byte_length` vector whose length doesn't divide into whole elements
+    InvalidByteLength {
+        length: usize,
+        element_size: usize,
+        field: &'static str,
+    },
 }
 
 impl core::fmt::Display for BeBytesError {
@@ -159,6 +179,13 @@ impl core::fmt::Display for BeBytesError {
             Self::InvalidBitField { value, max, field } => {
                 write!(f, "Value {value} exceeds maximum {max} for field {field}")
             }
+            Self::InvalidSignedBitField { value, max, field } => {
+                let min = -max - 1;
+                write!(
+                    f,
+                    "Value {value} is outside the range {min}..={max} for field {field}"
+                )
+            }
             Self::InvalidUtf8 { field } => {
                 write!(f, "Invalid UTF-8 sequence in field '{field}'")
             }
@@ -168,6 +195,19 @@ impl core::fmt::Display for BeBytesError {
             Self::InvalidChar { value } => {
                 write!(f, "Invalid Unicode code point: 0x{value:08X}")
             }
+            Self::InvalidVariantName { type_name } => {
+                write!(f, "Unknown variant name for type {type_name}")
+            }
+            Self::InvalidByteLength {
+                length,
+                element_size,
+                field,
+            } => {
+                write!(
+                    f,
+                    "Byte length {length} of field '{field}' is not a multiple of its element size {element_size}"
+                )
+            }
         }
     }
 }
@@ -180,6 +220,26 @@ impl std::error::Error for BeBytesError {}
 // #[cfg(not(feature = "std"))]
 // impl core::error::Error for BeBytesError {}
 
+/// Byte order picked at runtime, e.g. from a pcap magic number, a TIFF `II`/`MM` header or
+/// an ELF `EI_DATA` byte
+///
+/// [`BeBytes::try_from_bytes_with`], [`BeBytes::to_bytes_with`] and
+/// [`BeBytes::encode_to_with`] take it and dispatch to the big- or little-endian methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endian {
+    Big,
+    Little,
+}
+
+impl Endian {
+    /// Byte order of the target platform
+    pub const NATIVE: Self = if cfg!(target_endian = "big") {
+        Self::Big
+    } else {
+        Self::Little
+    };
+}
+
 pub trait BeBytes {
     fn field_size() -> usize;
 
@@ -266,4 +326,132 @@ pub trait BeBytes {
         buf.put_slice(&bytes);
         Ok(())
     }
+
+    /// Try to parse a struct from bytes in a byte order known only at runtime
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`BeBytes::try_from_be_bytes`] or [`BeBytes::try_from_le_bytes`]
+    fn try_from_bytes_with(
+        bytes: &'_ [u8],
+        endian: Endian,
+    ) -> core::result::Result<(Self, usize), BeBytesError>
+    where
+        Self: Sized,
+    {
+        match endian {
+            Endian::Big => Self::try_from_be_bytes(bytes),
+            Endian::Little => Self::try_from_le_bytes(bytes),
+        }
+    }
+
+    /// Convert to bytes in a byte order known only at runtime
+    #[cfg(feature = "std")]
+    fn to_bytes_with(&self, endian: Endian) -> std::vec::Vec<u8> {
+        match endian {
+            Endian::Big => self.to_be_bytes(),
+            Endian::Little => self.to_le_bytes(),
+        }
+    }
+
+    /// Convert to bytes in a byte order known only at runtime
+    #[cfg(not(feature = "std"))]
+    fn to_bytes_with(&self, endian: Endian) -> alloc::vec::Vec<u8> {
+        match endian {
+            Endian::Big => self.to_be_bytes(),
+            Endian::Little => self.to_le_bytes(),
+        }
+    }
+
+    /// Encode directly to a buffer in a byte order known only at runtime
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer doesn't have enough capacity
+    fn encode_to_with<B: BufMut>(
+        &self,
+        buf: &mut B,
+        endian: Endian,
+    ) -> core::result::Result<(), BeBytesError> {
+        match endian {
+            Endian::Big => self.encode_be_to(buf),
+            Endian::Little => self.encode_le_to(buf),
+        }
+    }
+}
+
+/// Data-carrying enums whose tag can be kept apart from the variant body
+///
+/// Implemented by `#[derive(BeBytes)]` for enums with tuple or struct variants.
+/// `BeBytes` encodes the tag followed by the body, while a struct field marked
+/// `#[bebytes(tag_from = header.msg_type)]` encodes only the body and takes the
+/// tag from an earlier field.
+pub trait TaggedBody: BeBytes {
+    /// Tag of the current variant
+    fn tag(&self) -> u128;
+
+    /// Parse the body of the variant selected by `tag` from big-endian bytes
+    ///
+    /// # Errors
+    ///
+    /// Returns `BeBytesError::InvalidDiscriminant` (or `InvalidDiscriminantLarge`) if no
+    /// variant uses `tag`, and any error of the variant's fields otherwise
+    fn try_from_be_body(
+        tag: u128,
+        bytes: &'_ [u8],
+    ) -> core::result::Result<(Self, usize), BeBytesError>
+    where
+        Self: Sized;
+
+    /// Parse the body of the variant selected by `tag` from little-endian bytes
+    ///
+    /// # Errors
+    ///
+    /// Returns `BeBytesError::InvalidDiscriminant` (or `InvalidDiscriminantLarge`) if no
+    /// variant uses `tag`, and any error of the variant's fields otherwise
+    fn try_from_le_body(
+        tag: u128,
+        bytes: &'_ [u8],
+    ) -> core::result::Result<(Self, usize), BeBytesError>
+    where
+        Self: Sized;
+
+    /// Encode the variant body without its tag in big-endian format
+    fn to_be_body(&self) -> Vec<u8>;
+
+    /// Encode the variant body without its tag in little-endian format
+    fn to_le_body(&self) -> Vec<u8>;
+}
+
+/// A run of bit fields that joins the bit stream of the struct embedding it
+///
+/// Implemented by `#[derive(BeBytes)]` for `#[bebytes(bits_struct)]` structs. Their
+/// `#[bits(N)]` fields don't need to complete a byte; a parent embeds the whole run with
+/// `#[bits(BITS)] field: Type` and checks byte completeness for the combined layout.
+pub trait BitsStruct: Sized {
+    /// Number of bits taken by the fields, at most 128
+    const BITS: usize;
+
+    /// Pack the fields into the low `BITS` bits, the first field in the most significant bits
+    fn to_msb0_bits(&self) -> u128;
+
+    /// Unpack fields packed by [`BitsStruct::to_msb0_bits`]. Bits above `BITS` are ignored
+    fn from_msb0_bits(bits: u128) -> Self;
+
+    /// Pack the fields into the low `BITS` bits, the first field in the least significant bits
+    fn to_lsb0_bits(&self) -> u128;
+
+    /// Unpack fields packed by [`BitsStruct::to_lsb0_bits`]. Bits above `BITS` are ignored
+    fn from_lsb0_bits(bits: u128) -> Self;
+}
+
+/// Compile-time check that a `#[bits(N)]` field holding a `BitsStruct` matches its width
+#[doc(hidden)]
+pub struct BitsStructWidth<T, const N: usize>(core::marker::PhantomData<T>);
+
+impl<T: BitsStruct, const N: usize> BitsStructWidth<T, N> {
+    pub const CHECK: () = assert!(
+        T::BITS == N,
+        "#[bits(N)] on a bits_struct field must match the struct's BITS"
+    );
 }
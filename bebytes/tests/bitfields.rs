@@ -5,6 +5,13 @@
 //! - Boundary crossing bit fields
 //! - Multi-byte bit fields
 //! - Edge cases and limits
+//! - Signed bit fields
+//! - `bool` single-bit flags
+//! - Arrays packed with `#[bits(N)]` per element
+//! - Explicit bit positions with `#[bits(a..b)]`
+//! - Fixed bit order with `#[bebytes(bit_order = "lsb0")]`
+//! - Sub-byte `#[bebytes(bits_struct)]` types embedded with `#[bits(N)]`
+//! - C bit-field layout with `#[bebytes(c_layout)]`
 
 use bebytes::BeBytes;
 
@@ -289,3 +296,903 @@ mod endianness {
         assert_eq!(le_decoded, data);
     }
 }
+
+mod signed_bitfields {
+    use super::*;
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct SensorHeader {
+        #[bits(3)]
+        kind: u8,
+        #[bits(5)]
+        offset: i8,
+        #[bits(12)]
+        temperature: i16,
+        #[bits(4)]
+        delta: i32,
+    }
+
+    #[test]
+    fn test_negative_values_round_trip() {
+        let header = SensorHeader {
+            kind: 5,
+            offset: -3,
+            temperature: -2048,
+            delta: -8,
+        };
+
+        let bytes = header.to_be_bytes();
+        assert_eq!(bytes, vec![0b1011_1101, 0x80, 0x08]);
+        let (decoded, consumed) = SensorHeader::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 3);
+        assert_eq!(decoded, header);
+
+        let (decoded, _) = SensorHeader::try_from_le_bytes(&header.to_le_bytes()).unwrap();
+        assert_eq!(decoded, header);
+    }
+
+    #[test]
+    fn test_signed_bounds() {
+        for (offset, temperature, delta) in [(-16, -2048, -8), (15, 2047, 7), (0, -1, -1)] {
+            let header = SensorHeader::new(0, offset, temperature, delta);
+            let (decoded, _) = SensorHeader::try_from_be_bytes(&header.to_be_bytes()).unwrap();
+            assert_eq!(decoded, header);
+            let (decoded, _) = SensorHeader::try_from_le_bytes(&header.to_le_bytes()).unwrap();
+            assert_eq!(decoded, header);
+        }
+    }
+
+    #[test]
+    fn test_top_bit_is_sign_extended() {
+        let (decoded, _) = SensorHeader::try_from_be_bytes(&[0b0001_0000, 0x7F, 0xF0]).unwrap();
+        assert_eq!(decoded.offset, -16);
+        assert_eq!(decoded.temperature, 2047);
+        assert_eq!(decoded.delta, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value 16 is outside the range -16..=15 for field offset")]
+    fn test_constructor_rejects_out_of_range() {
+        let _ = SensorHeader::new(0, 16, 0, 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Value -2049 is outside the range -2048..=2047 for field temperature"
+    )]
+    fn test_encoding_rejects_out_of_range() {
+        let header = SensorHeader {
+            kind: 0,
+            offset: 0,
+            temperature: -2049,
+            delta: 0,
+        };
+        let _ = header.to_be_bytes();
+    }
+}
+
+mod bool_flags {
+    use super::*;
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    struct TcpFlags {
+        #[bits(4)]
+        data_offset: u8,
+        #[bits(3)]
+        reserved: u8,
+        #[bits(1)]
+        ns: bool,
+        #[bits(1)]
+        cwr: bool,
+        #[bits(1)]
+        ece: bool,
+        #[bits(1)]
+        urg: bool,
+        #[bits(1)]
+        ack: bool,
+        #[bits(1)]
+        psh: bool,
+        #[bits(1)]
+        rst: bool,
+        #[bits(1)]
+        syn: bool,
+        #[bits(1)]
+        fin: bool,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct DnsFlags {
+        #[bits(1)]
+        qr: bool,
+        #[bits(4)]
+        opcode: u8,
+        #[bits(1)]
+        aa: bool,
+        #[bits(1)]
+        tc: bool,
+        #[bits(1)]
+        rd: bool,
+        #[bits(1)]
+        ra: bool,
+        #[bits(3)]
+        z: u8,
+        #[bits(4)]
+        rcode: u16,
+    }
+
+    fn syn_ack() -> TcpFlags {
+        TcpFlags {
+            data_offset: 5,
+            reserved: 0,
+            ns: false,
+            cwr: false,
+            ece: false,
+            urg: false,
+            ack: true,
+            psh: false,
+            rst: false,
+            syn: true,
+            fin: false,
+        }
+    }
+
+    #[test]
+    fn test_flags_round_trip() {
+        let flags = syn_ack();
+        assert_eq!(TcpFlags::field_size(), 2);
+
+        let bytes = flags.to_be_bytes();
+        assert_eq!(bytes, vec![0x50, 0x12]);
+        let (decoded, consumed) = TcpFlags::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 2);
+        assert_eq!(decoded, flags);
+
+        let (decoded, _) = TcpFlags::try_from_le_bytes(&flags.to_le_bytes()).unwrap();
+        assert_eq!(decoded, flags);
+    }
+
+    #[test]
+    fn test_set_bits_decode_as_true() {
+        let (decoded, _) = TcpFlags::try_from_be_bytes(&[0x51, 0xFF]).unwrap();
+        assert_eq!(decoded.data_offset, 5);
+        assert!(decoded.ns);
+        assert!(decoded.cwr && decoded.ece && decoded.urg && decoded.ack);
+        assert!(decoded.psh && decoded.rst && decoded.syn && decoded.fin);
+
+        let (decoded, _) = TcpFlags::try_from_be_bytes(&[0xFE, 0x00]).unwrap();
+        assert_eq!(decoded.reserved, 7);
+        assert!(!decoded.ns && !decoded.syn);
+    }
+
+    #[test]
+    fn test_flags_between_wider_fields() {
+        let flags = DnsFlags::new(true, 2, false, true, true, false, 0, 3);
+        assert_eq!(DnsFlags::field_size(), 2);
+
+        let bytes = flags.to_be_bytes();
+        assert_eq!(bytes, vec![0b1001_0011, 0b0000_0011]);
+        let (decoded, _) = DnsFlags::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(decoded, flags);
+
+        let (decoded, _) = DnsFlags::try_from_le_bytes(&flags.to_le_bytes()).unwrap();
+        assert_eq!(decoded, flags);
+    }
+}
+
+mod bit_arrays {
+    use super::*;
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct BcdTime {
+        #[bits(4)]
+        digits: [u8; 6],
+        #[bits(2)]
+        channels: [u8; 3],
+        #[bits(1)]
+        valid: bool,
+        #[bits(1)]
+        dst: bool,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Samples {
+        #[bits(4)]
+        kind: u8,
+        #[bits(12)]
+        values: [u16; 2],
+        #[bits(4)]
+        deltas: [i8; 2],
+        #[bits(1)]
+        flags: [bool; 4],
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    enum Frame {
+        #[bebytes(tag = 1)]
+        Palette {
+            #[bits(4)]
+            indices: [u8; 4],
+        },
+    }
+
+    #[test]
+    fn test_nibble_array_round_trip() {
+        let time = BcdTime {
+            digits: [1, 2, 3, 4, 5, 9],
+            channels: [0, 1, 3],
+            valid: true,
+            dst: false,
+        };
+        assert_eq!(BcdTime::field_size(), 4);
+
+        let be_bytes = time.to_be_bytes();
+        assert_eq!(be_bytes, vec![0x12, 0x34, 0x59, 0b0001_1110]);
+        let (decoded, consumed) = BcdTime::try_from_be_bytes(&be_bytes).unwrap();
+        assert_eq!(consumed, 4);
+        assert_eq!(decoded, time);
+
+        let (decoded, _) = BcdTime::try_from_le_bytes(&time.to_le_bytes()).unwrap();
+        assert_eq!(decoded, time);
+    }
+
+    #[test]
+    fn test_multi_byte_and_signed_elements() {
+        let samples = Samples {
+            kind: 0xA,
+            values: [0xFFF, 0x123],
+            deltas: [-8, 7],
+            flags: [true, false, false, true],
+        };
+        assert_eq!(Samples::field_size(), 5);
+
+        let bytes = samples.to_be_bytes();
+        assert_eq!(bytes, vec![0xAF, 0xFF, 0x12, 0x38, 0x79]);
+        let (decoded, _) = Samples::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(decoded, samples);
+
+        let (decoded, _) = Samples::try_from_le_bytes(&samples.to_le_bytes()).unwrap();
+        assert_eq!(decoded, samples);
+    }
+
+    #[test]
+    fn test_array_in_enum_variant() {
+        let frame = Frame::Palette {
+            indices: [0xA, 0xB, 0xC, 0xD],
+        };
+
+        let bytes = frame.to_be_bytes();
+        assert_eq!(bytes, vec![1, 0xAB, 0xCD]);
+        let (decoded, _) = Frame::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(decoded, frame);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value 4 exceeds maximum 3 for field channels[1]")]
+    fn test_constructor_reports_element_index() {
+        let _ = BcdTime::new([1, 2, 3, 4, 5, 6], [0, 4, 0], false, false);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value 16 exceeds maximum 15 for field digits[2]")]
+    fn test_encoding_reports_element_index() {
+        let time = BcdTime {
+            digits: [1, 2, 16, 4, 5, 6],
+            channels: [0, 0, 0],
+            valid: false,
+            dst: false,
+        };
+        let _ = time.to_be_bytes();
+    }
+
+    #[test]
+    #[should_panic(expected = "Value 8 is outside the range -8..=7 for field deltas[1]")]
+    fn test_signed_element_out_of_range() {
+        let _ = Samples::new(0, [0, 0], [0, 8], [false; 4]);
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct WideNibbles {
+        #[bits(4)]
+        nibbles: [u128; 2],
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Value 340282366920938463463374607431768211455 exceeds maximum 15 for field nibbles[1]"
+    )]
+    fn test_u128_element_above_i128_max() {
+        let value = WideNibbles {
+            nibbles: [1, u128::MAX],
+        };
+        let _ = value.to_be_bytes();
+    }
+}
+
+mod positioned_bits {
+    use super::*;
+    use bebytes::BeBytesError;
+
+    // Declared in the order of the spec: bit 0 = enable, bits 15..12 = opcode.
+    // Bits 1..4 are reserved
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Instruction {
+        #[bits(0..1)]
+        enable: bool,
+        #[bits(12..16)]
+        opcode: u8,
+        #[bits(4..8)]
+        register: u8,
+        #[bits(8..=11)]
+        mode: u8,
+        length: u16,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Mixed {
+        #[bits(4)]
+        version: u8,
+        #[bits(4)]
+        flags: u8,
+        #[bits(13..16)]
+        priority: u8,
+        #[bits(0..13)]
+        offset: u16,
+        // A field without a range ends the group
+        separator: u8,
+        #[bits(12..16)]
+        top: u8,
+        trailer: u8,
+    }
+
+    fn instruction() -> Instruction {
+        Instruction {
+            enable: true,
+            opcode: 0xA,
+            register: 0x3,
+            mode: 0x5,
+            length: 0x0102,
+        }
+    }
+
+    #[test]
+    fn test_fields_follow_register_positions() {
+        let instruction = instruction();
+        assert_eq!(Instruction::field_size(), 4);
+
+        let be_bytes = instruction.to_be_bytes();
+        assert_eq!(be_bytes, vec![0xA5, 0x31, 0x01, 0x02]);
+        let (decoded, consumed) = Instruction::try_from_be_bytes(&be_bytes).unwrap();
+        assert_eq!(consumed, 4);
+        assert_eq!(decoded, instruction);
+    }
+
+    #[test]
+    fn test_little_endian_register() {
+        let instruction = instruction();
+
+        let le_bytes = instruction.to_le_bytes();
+        assert_eq!(le_bytes, vec![0x31, 0xA5, 0x02, 0x01]);
+        let (decoded, _) = Instruction::try_from_le_bytes(&le_bytes).unwrap();
+        assert_eq!(decoded, instruction);
+    }
+
+    #[test]
+    fn test_reserved_bits_are_ignored() {
+        let (decoded, _) = Instruction::try_from_be_bytes(&[0xA5, 0x3F, 0x01, 0x02]).unwrap();
+        assert_eq!(decoded, instruction());
+        assert_eq!(decoded.to_be_bytes(), vec![0xA5, 0x31, 0x01, 0x02]);
+    }
+
+    #[test]
+    fn test_groups_after_sequential_bits() {
+        let mixed = Mixed {
+            version: 4,
+            flags: 2,
+            priority: 5,
+            offset: 0x1234,
+            separator: 0x77,
+            top: 0xC,
+            trailer: 0xEE,
+        };
+        assert_eq!(Mixed::field_size(), 7);
+
+        // `top` leaves the low byte of its group reserved
+        let be_bytes = mixed.to_be_bytes();
+        assert_eq!(be_bytes, vec![0x42, 0xB2, 0x34, 0x77, 0xC0, 0x00, 0xEE]);
+        let (decoded, consumed) = Mixed::try_from_be_bytes(&be_bytes).unwrap();
+        assert_eq!(consumed, 7);
+        assert_eq!(decoded, mixed);
+
+        let (decoded, _) = Mixed::try_from_le_bytes(&mixed.to_le_bytes()).unwrap();
+        assert_eq!(decoded, mixed);
+    }
+
+    #[test]
+    fn test_truncated_group() {
+        assert_eq!(
+            Instruction::try_from_be_bytes(&[0xA5]),
+            Err(BeBytesError::InsufficientData {
+                expected: 2,
+                actual: 1,
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn test_constructor_checks_range_width() {
+        let _ = Instruction::new(false, 0x10, 0, 0, 0);
+    }
+}
+
+// Binary literals are grouped by field
+#[allow(clippy::unusual_byte_groupings)]
+mod bit_order {
+    use super::*;
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    #[bebytes(bit_order = "msb0")]
+    struct Msb0 {
+        #[bits(3)]
+        kind: u8,
+        #[bits(5)]
+        value: u8,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    #[bebytes(bit_order = "lsb0")]
+    struct Lsb0 {
+        #[bits(3)]
+        kind: u8,
+        #[bits(5)]
+        value: u8,
+    }
+
+    // A CAN signal layout: bits are numbered from the least significant bit
+    #[derive(BeBytes, Debug, PartialEq)]
+    #[bebytes(bit_order = "lsb0")]
+    struct CanStatus {
+        #[bits(1)]
+        ready: bool,
+        #[bits(3)]
+        mode: u8,
+        #[bits(12)]
+        speed: u16,
+        counter: u16,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    #[bebytes(bit_order = "lsb0")]
+    struct Register {
+        #[bits(12..16)]
+        opcode: u8,
+        #[bits(0..8)]
+        operand: u8,
+    }
+
+    #[test]
+    fn test_same_values_mirror_layout() {
+        let msb0 = Msb0 {
+            kind: 0b101,
+            value: 0b00011,
+        };
+        let lsb0 = Lsb0 {
+            kind: 0b101,
+            value: 0b00011,
+        };
+
+        // msb0 starts at the most significant bit, lsb0 at the least significant bit
+        assert_eq!(msb0.to_be_bytes(), vec![0b101_00011]);
+        assert_eq!(lsb0.to_be_bytes(), vec![0b00011_101]);
+
+        let (decoded, _) = Lsb0::try_from_be_bytes(&[0b00011_101]).unwrap();
+        assert_eq!(decoded, lsb0);
+        let (decoded, _) = Msb0::try_from_be_bytes(&[0b101_00011]).unwrap();
+        assert_eq!(decoded, msb0);
+    }
+
+    #[test]
+    fn test_bit_order_applies_to_both_byte_orders() {
+        let msb0 = Msb0 {
+            kind: 0b101,
+            value: 0b00011,
+        };
+        assert_eq!(msb0.to_le_bytes(), vec![0b101_00011]);
+        let (decoded, _) = Msb0::try_from_le_bytes(&[0b101_00011]).unwrap();
+        assert_eq!(decoded, msb0);
+
+        let lsb0 = Lsb0 {
+            kind: 0b101,
+            value: 0b00011,
+        };
+        assert_eq!(lsb0.to_le_bytes(), vec![0b00011_101]);
+    }
+
+    #[test]
+    fn test_byte_aligned_fields_follow_method() {
+        let status = CanStatus {
+            ready: true,
+            mode: 5,
+            speed: 0x123,
+            counter: 0x0102,
+        };
+
+        let be_bytes = status.to_be_bytes();
+        assert_eq!(be_bytes, vec![0x3B, 0x12, 0x01, 0x02]);
+        let (decoded, _) = CanStatus::try_from_be_bytes(&be_bytes).unwrap();
+        assert_eq!(decoded, status);
+
+        let le_bytes = status.to_le_bytes();
+        assert_eq!(le_bytes, vec![0x3B, 0x12, 0x02, 0x01]);
+        let (decoded, _) = CanStatus::try_from_le_bytes(&le_bytes).unwrap();
+        assert_eq!(decoded, status);
+    }
+
+    #[test]
+    fn test_positions_in_lsb0_group() {
+        let register = Register {
+            opcode: 0xA,
+            operand: 0x42,
+        };
+
+        // The group is laid out least significant byte first
+        let bytes = register.to_be_bytes();
+        assert_eq!(bytes, vec![0x42, 0xA0]);
+        assert_eq!(register.to_le_bytes(), bytes);
+        let (decoded, _) = Register::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(decoded, register);
+    }
+}
+
+mod bits_structs {
+    use super::*;
+    use bebytes::BitsStruct;
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    #[bebytes(bits_struct)]
+    struct Channel {
+        #[bits(4)]
+        band: u8,
+        #[bits(8)]
+        number: u8,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Frame {
+        #[bits(4)]
+        version: u8,
+        #[bits(12)]
+        channel: Channel,
+        crc: u8,
+    }
+
+    // The same layout as `Frame` with the channel written out
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct InlineFrame {
+        #[bits(4)]
+        version: u8,
+        #[bits(4)]
+        band: u8,
+        #[bits(8)]
+        number: u8,
+        crc: u8,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    #[bebytes(bits_struct)]
+    struct Level {
+        #[bits(1)]
+        urgent: bool,
+        #[bits(3)]
+        delta: i8,
+    }
+
+    // Bits structs nest, the total only has to complete a byte in the parent
+    #[derive(BeBytes, Debug, PartialEq)]
+    #[bebytes(bits_struct)]
+    struct Status {
+        #[bits(4)]
+        level: Level,
+        #[bits(12)]
+        channel: Channel,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Report {
+        #[bits(16)]
+        status: Status,
+        #[bits(4)]
+        spare: u8,
+        #[bits(4)]
+        level: Level,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    #[bebytes(bit_order = "lsb0")]
+    struct Lsb0Frame {
+        #[bits(4)]
+        version: u8,
+        #[bits(12)]
+        channel: Channel,
+    }
+
+    #[test]
+    fn test_width() {
+        assert_eq!(Channel::BITS, 12);
+        assert_eq!(Level::BITS, 4);
+        assert_eq!(Status::BITS, 16);
+        assert_eq!(Frame::field_size(), 3);
+    }
+
+    #[test]
+    fn test_embedded_round_trip() {
+        let frame = Frame {
+            version: 0x1,
+            channel: Channel {
+                band: 0x2,
+                number: 0x34,
+            },
+            crc: 0xFF,
+        };
+
+        let bytes = frame.to_be_bytes();
+        assert_eq!(bytes, vec![0x12, 0x34, 0xFF]);
+        let (decoded, consumed) = Frame::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 3);
+        assert_eq!(decoded, frame);
+
+        let (decoded, _) = Frame::try_from_le_bytes(&frame.to_le_bytes()).unwrap();
+        assert_eq!(decoded, frame);
+    }
+
+    #[test]
+    fn test_same_bytes_as_inline_fields() {
+        let frame = Frame::new(
+            0x7,
+            Channel {
+                band: 0xA,
+                number: 0x5C,
+            },
+            0x01,
+        );
+        let inline = InlineFrame::new(0x7, 0xA, 0x5C, 0x01);
+
+        assert_eq!(frame.to_be_bytes(), inline.to_be_bytes());
+        assert_eq!(frame.to_le_bytes(), inline.to_le_bytes());
+    }
+
+    #[test]
+    fn test_nested_bits_structs() {
+        let report = Report {
+            status: Status {
+                level: Level {
+                    urgent: true,
+                    delta: -3,
+                },
+                channel: Channel {
+                    band: 0x9,
+                    number: 0x87,
+                },
+            },
+            spare: 0,
+            level: Level {
+                urgent: false,
+                delta: 2,
+            },
+        };
+
+        let bytes = report.to_be_bytes();
+        assert_eq!(bytes, vec![0b1101_1001, 0x87, 0b0000_0010]);
+        let (decoded, _) = Report::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(decoded, report);
+
+        let (decoded, _) = Report::try_from_le_bytes(&report.to_le_bytes()).unwrap();
+        assert_eq!(decoded, report);
+    }
+
+    #[test]
+    fn test_parent_bit_order() {
+        let frame = Lsb0Frame {
+            version: 0x1,
+            channel: Channel {
+                band: 0x2,
+                number: 0x34,
+            },
+        };
+
+        // lsb0 starts with the version in the low nibble, the band follows it
+        let bytes = frame.to_be_bytes();
+        assert_eq!(bytes, vec![0x21, 0x34]);
+        let (decoded, _) = Lsb0Frame::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(decoded, frame);
+    }
+
+    #[test]
+    fn test_packed_bits() {
+        let channel = Channel {
+            band: 0x2,
+            number: 0x34,
+        };
+        assert_eq!(channel.to_msb0_bits(), 0x234);
+        assert_eq!(channel.to_lsb0_bits(), 0x342);
+        assert_eq!(Channel::from_msb0_bits(0x234), channel);
+        assert_eq!(Channel::from_lsb0_bits(0x342), channel);
+
+        let level = Level::from_msb0_bits(0b0111);
+        assert!(!level.urgent);
+        assert_eq!(level.delta, -1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value of field band is out of range")]
+    fn test_constructor_checks_fields() {
+        let _ = Channel::new(16, 0);
+    }
+}
+
+mod c_layout {
+    use super::*;
+
+    // The byte images below are what GCC produces for the equivalent C structs on x86-64:
+    //
+    // struct Flags { uint8_t a:3; uint8_t b:6; uint16_t c:10; uint32_t d; };
+    // struct Reg { uint32_t mode:4; uint32_t count:20; uint8_t flag:1; uint16_t x; };
+    // struct Signed { bool on:1; int8_t delta:4; int16_t v:12; };
+    // struct Mixed { uint8_t tag; uint64_t big:40; uint64_t rest:30; uint8_t raw[3]; float ratio; };
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    #[bebytes(c_layout)]
+    struct Flags {
+        #[bits(3)]
+        a: u8,
+        #[bits(6)]
+        b: u8,
+        #[bits(10)]
+        c: u16,
+        d: u32,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    #[bebytes(c_layout)]
+    struct Reg {
+        #[bits(4)]
+        mode: u32,
+        #[bits(20)]
+        count: u32,
+        #[bits(1)]
+        flag: u8,
+        x: u16,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    #[bebytes(c_layout)]
+    struct Signed {
+        #[bits(1)]
+        on: bool,
+        #[bits(4)]
+        delta: i8,
+        #[bits(12)]
+        v: i16,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    #[bebytes(c_layout)]
+    struct Mixed {
+        tag: u8,
+        #[bits(40)]
+        big: u64,
+        #[bits(30)]
+        rest: u64,
+        raw: [u8; 3],
+        ratio: f32,
+    }
+
+    #[test]
+    fn test_storage_unit_boundaries() {
+        let flags = Flags {
+            a: 5,
+            b: 0x2A,
+            c: 0x155,
+            d: 0x1122_3344,
+        };
+
+        let bytes = flags.to_le_bytes();
+        assert_eq!(bytes, vec![0x05, 0x2A, 0x55, 0x01, 0x44, 0x33, 0x22, 0x11]);
+        assert_eq!(Flags::field_size(), 8);
+        let (decoded, consumed) = Flags::try_from_le_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 8);
+        assert_eq!(decoded, flags);
+    }
+
+    #[test]
+    fn test_shared_unit_and_tail_padding() {
+        let reg = Reg::new(0xA, 0x12345, 1, 0xBEEF);
+
+        let bytes = reg.to_le_bytes();
+        assert_eq!(bytes, vec![0x5A, 0x34, 0x12, 0x01, 0xEF, 0xBE, 0x00, 0x00]);
+        let (decoded, consumed) = Reg::try_from_le_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 8);
+        assert_eq!(decoded, reg);
+    }
+
+    #[test]
+    fn test_signed_and_bool_fields() {
+        let signed = Signed {
+            on: true,
+            delta: -3,
+            v: -2,
+        };
+
+        let bytes = signed.to_le_bytes();
+        assert_eq!(bytes, vec![0x1B, 0x00, 0xFE, 0x0F]);
+        let (decoded, _) = Signed::try_from_le_bytes(&bytes).unwrap();
+        assert_eq!(decoded, signed);
+    }
+
+    #[test]
+    fn test_wide_units_arrays_and_floats() {
+        let mixed = Mixed {
+            tag: 0x7E,
+            big: 0x12_3456_789A,
+            rest: 0x2BCD_EF01,
+            raw: [1, 2, 3],
+            ratio: 1.5,
+        };
+
+        let bytes = mixed.to_le_bytes();
+        assert_eq!(
+            bytes,
+            vec![
+                0x7E, 0x9A, 0x78, 0x56, 0x34, 0x12, 0x00, 0x00, 0x01, 0xEF, 0xCD, 0x2B, 0x01, 0x02,
+                0x03, 0x00, 0x00, 0x00, 0xC0, 0x3F, 0x00, 0x00, 0x00, 0x00,
+            ]
+        );
+        assert_eq!(Mixed::field_size(), 24);
+        let (decoded, consumed) = Mixed::try_from_le_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 24);
+        assert_eq!(decoded, mixed);
+    }
+
+    #[test]
+    fn test_big_endian_image() {
+        // A big-endian target allocates from the most significant bit of each unit
+        let flags = Flags {
+            a: 5,
+            b: 0x2A,
+            c: 0x155,
+            d: 0x1122_3344,
+        };
+
+        let bytes = flags.to_be_bytes();
+        assert_eq!(bytes, vec![0xA0, 0xA8, 0x55, 0x40, 0x11, 0x22, 0x33, 0x44]);
+        let (decoded, _) = Flags::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(decoded, flags);
+    }
+
+    #[test]
+    fn test_padding_is_ignored() {
+        let bytes = [0xFD, 0xFF, 0xFE, 0xFF];
+        let (decoded, _) = Signed::try_from_le_bytes(&bytes).unwrap();
+        assert_eq!(
+            decoded,
+            Signed {
+                on: true,
+                delta: -2,
+                v: -2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_truncated_padding() {
+        let bytes = [0x5A, 0x34, 0x12, 0x01, 0xEF, 0xBE];
+        let result = Reg::try_from_le_bytes(&bytes);
+        assert_eq!(
+            result,
+            Err(bebytes::BeBytesError::InsufficientData {
+                expected: 8,
+                actual: 6,
+            })
+        );
+    }
+}
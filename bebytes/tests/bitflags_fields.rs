@@ -0,0 +1,142 @@
+//! `bitflags` field tests for BeBytes
+//!
+//! This module tests:
+//! - Flags types from the `bitflags` crate encoded as their integer type
+//! - Flags packed into bit fields with `#[bits(N)]`
+//! - Unknown bit policies (`retain`, `truncate`, `reject`)
+//! - Byte order of multi-byte flags
+
+use bebytes::bitflags::bitflags;
+use bebytes::{BeBytes, BeBytesError};
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Permissions: u8 {
+        const READ = 0b0001;
+        const WRITE = 0b0010;
+        const EXECUTE = 0b0100;
+    }
+}
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Options: u16 {
+        const ACK = 0x0001;
+        const PUSH = 0x0100;
+    }
+}
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct File {
+    id: u16,
+    #[bebytes(bitflags(u8))]
+    permissions: Permissions,
+}
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct Segment {
+    #[bebytes(bitflags(u16))]
+    options: Options,
+    length: u8,
+}
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct Packed {
+    #[bits(4)]
+    version: u8,
+    #[bits(4)]
+    #[bebytes(bitflags(u8))]
+    permissions: Permissions,
+}
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct Truncating {
+    #[bebytes(bitflags(u8), unknown_bits = "truncate")]
+    permissions: Permissions,
+}
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct Strict {
+    #[bebytes(bitflags(u8), unknown_bits = "reject")]
+    permissions: Permissions,
+}
+
+#[test]
+fn test_flags_round_trip() {
+    let file = File {
+        id: 7,
+        permissions: Permissions::READ | Permissions::EXECUTE,
+    };
+    assert_eq!(File::field_size(), 3);
+
+    let bytes = file.to_be_bytes();
+    assert_eq!(bytes, vec![0, 7, 0b0101]);
+    let (decoded, consumed) = File::try_from_be_bytes(&bytes).unwrap();
+    assert_eq!(consumed, 3);
+    assert_eq!(decoded, file);
+}
+
+#[test]
+fn test_multi_byte_flags_byte_order() {
+    let segment = Segment {
+        options: Options::ACK | Options::PUSH,
+        length: 3,
+    };
+    assert_eq!(segment.to_be_bytes(), vec![0x01, 0x01, 3]);
+
+    let segment = Segment {
+        options: Options::PUSH,
+        length: 3,
+    };
+    assert_eq!(segment.to_be_bytes(), vec![0x01, 0x00, 3]);
+    assert_eq!(segment.to_le_bytes(), vec![0x00, 0x01, 3]);
+    let (decoded, _) = Segment::try_from_le_bytes(&segment.to_le_bytes()).unwrap();
+    assert_eq!(decoded, segment);
+}
+
+#[test]
+fn test_flags_in_bit_field() {
+    let packed = Packed {
+        version: 2,
+        permissions: Permissions::READ | Permissions::WRITE,
+    };
+    assert_eq!(Packed::field_size(), 1);
+
+    let bytes = packed.to_be_bytes();
+    assert_eq!(bytes, vec![0x23]);
+    let (decoded, _) = Packed::try_from_be_bytes(&bytes).unwrap();
+    assert_eq!(decoded, packed);
+}
+
+#[test]
+fn test_unknown_bits_are_retained_by_default() {
+    let (decoded, _) = File::try_from_be_bytes(&[0, 1, 0b1001]).unwrap();
+    assert!(decoded.permissions.contains(Permissions::READ));
+    assert_eq!(decoded.permissions.bits(), 0b1001);
+    assert_eq!(decoded.to_be_bytes(), vec![0, 1, 0b1001]);
+}
+
+#[test]
+fn test_unknown_bits_truncate() {
+    let (decoded, _) = Truncating::try_from_be_bytes(&[0b1011]).unwrap();
+    assert_eq!(decoded.permissions, Permissions::READ | Permissions::WRITE);
+}
+
+#[test]
+fn test_unknown_bits_reject() {
+    let (decoded, _) = Strict::try_from_be_bytes(&[0b0010]).unwrap();
+    assert_eq!(decoded.permissions, Permissions::WRITE);
+
+    let result = Strict::try_from_be_bytes(&[0b1010]);
+    assert!(matches!(
+        result,
+        Err(BeBytesError::InvalidDiscriminantLarge { value: 0b1010, .. })
+    ));
+}
+
+#[test]
+fn test_constructor_takes_flags() {
+    let file = File::new(1, Permissions::WRITE);
+    assert_eq!(file.permissions, Permissions::WRITE);
+    assert_eq!(file.to_be_bytes(), vec![0, 1, 0b0010]);
+}
@@ -8,6 +8,18 @@ fn ui_tests() {
     t.compile_fail("tests/compile_time/attributes/bits_and_size_conflict.rs");
     t.compile_fail("tests/compile_time/attributes/fromfield_and_with_conflict.rs");
     t.compile_fail("tests/compile_time/attributes/multiple_endian_attrs.rs");
+    t.compile_fail("tests/compile_time/attributes/transparent_multiple_fields.rs");
+    t.compile_fail("tests/compile_time/attributes/tag_from_with_size.rs");
+    t.compile_fail("tests/compile_time/attributes/bitflags_with_size.rs");
+    t.compile_fail("tests/compile_time/attributes/bitflags_non_integer.rs");
+    t.compile_fail("tests/compile_time/attributes/byte_length_without_size.rs");
+    t.compile_fail("tests/compile_time/attributes/option_size_on_primitive.rs");
+    t.compile_fail("tests/compile_time/attributes/option_mode_conflict.rs");
+    t.compile_fail("tests/compile_time/attributes/bytes_exceeds_type.rs");
+    t.compile_fail("tests/compile_time/attributes/invalid_bit_order.rs");
+    t.compile_fail("tests/compile_time/attributes/c_layout_unsupported_field.rs");
+    t.compile_fail("tests/compile_time/attributes/endian_on_bit_field.rs");
+    t.compile_fail("tests/compile_time/attributes/word_order_unsupported_type.rs");
 
     // ===== BIT FIELD TESTS =====
     t.compile_fail("tests/compile_time/bit_fields/incomplete_byte.rs");
@@ -15,12 +27,23 @@ fn ui_tests() {
     t.compile_fail("tests/compile_time/bit_fields/exceeds_type_size.rs");
     t.compile_fail("tests/compile_time/bit_fields/bits_on_non_numeric.rs");
     t.compile_fail("tests/compile_time/bit_fields/multiple_bits_attributes.rs");
+    t.compile_fail("tests/compile_time/bit_fields/array_incomplete_byte.rs");
+    t.compile_fail("tests/compile_time/bit_fields/overlapping_positions.rs");
+    t.compile_fail("tests/compile_time/bit_fields/unaligned_positions.rs");
+    t.compile_fail("tests/compile_time/bit_fields/bits_struct_missing_bits.rs");
 
     // ===== ENUM TESTS =====
     t.compile_fail("tests/compile_time/enums/duplicate_discriminants.rs");
-    t.compile_fail("tests/compile_time/enums/data_variants.rs");
+    t.compile_fail("tests/compile_time/enums/duplicate_tags.rs");
+    t.compile_fail("tests/compile_time/enums/tag_type_too_small.rs");
+    t.compile_fail("tests/compile_time/enums/other_wrong_type.rs");
+    t.compile_fail("tests/compile_time/enums/multiple_other_variants.rs");
+    t.compile_fail("tests/compile_time/enums/unevaluable_discriminant.rs");
+    t.compile_fail("tests/compile_time/enums/overlapping_ranges.rs");
+    t.compile_fail("tests/compile_time/enums/constant_inside_range.rs");
     #[cfg(feature = "std")]
     t.compile_fail("tests/compile_time/enums/invalid_flag_enum.rs");
+    t.compile_fail("tests/compile_time/enums/invalid_unknown_bits.rs");
     #[cfg(feature = "std")]
     t.compile_fail("tests/compile_time/enums/explicit_type_too_small.rs");
 
@@ -61,4 +84,5 @@ fn ui_tests() {
     #[cfg(feature = "std")]
     t.pass("tests/compile_time/custom_result_alias.rs");
     t.pass("tests/compile_time/option.rs");
+    t.pass("tests/compile_time/enums/custom_display.rs");
 }
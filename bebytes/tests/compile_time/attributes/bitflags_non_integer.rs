@@ -0,0 +1,11 @@
+// This test verifies that bitflags(..) names the integer type of the flags.
+
+use bebytes::BeBytes;
+
+#[derive(BeBytes)]
+struct Invalid {
+    #[bebytes(bitflags(f32))] // Error: not an integer type
+    flags: u32,
+}
+
+fn main() {}
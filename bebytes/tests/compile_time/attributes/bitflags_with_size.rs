@@ -0,0 +1,14 @@
+// This test verifies that a bitflags field cannot use size attributes;
+// only #[bits(N)] may change how its integer is encoded.
+
+use bebytes::BeBytes;
+
+#[derive(BeBytes)]
+struct Invalid {
+    count: u8,
+    #[bebytes(bitflags(u8))]
+    #[FromField(count)]
+    flags: Vec<u8>, // Error: bitflags can only be combined with #[bits(N)]
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+// This test verifies that byte_length needs a size to reinterpret.
+
+use bebytes::BeBytes;
+
+#[derive(BeBytes)]
+struct Invalid {
+    #[bebytes(byte_length)]
+    samples: Vec<u16>, // Error: byte_length without #[With(size(N))] or #[FromField(..)]
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+// This test verifies that a byte width cannot exceed the size of the integer type.
+
+use bebytes::BeBytes;
+
+#[derive(BeBytes)]
+struct Invalid {
+    #[bebytes(bytes = 5)]
+    length: u32, // Error: u32 only has 4 bytes
+}
+
+fn main() {}
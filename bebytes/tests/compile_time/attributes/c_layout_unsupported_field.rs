@@ -0,0 +1,15 @@
+// This test verifies that c_layout structs only hold fields with a C equivalent.
+
+use bebytes::BeBytes;
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[derive(BeBytes, Debug, PartialEq)]
+#[bebytes(c_layout)]
+struct Record {
+    #[bits(4)]
+    kind: u8,
+    payload: Vec<u8>, // Error: no C layout for a vector
+}
+
+fn main() {}
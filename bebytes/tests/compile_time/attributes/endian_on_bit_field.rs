@@ -0,0 +1,16 @@
+// This test verifies that bit fields don't take a byte order of their own.
+
+use bebytes::BeBytes;
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct Control {
+    #[bits(4)]
+    #[bebytes(endian = "little")] // Error: bit fields follow the struct's bit order
+    mode: u8,
+    #[bits(12)]
+    length: u16,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+// This test verifies that bit_order only accepts "msb0" and "lsb0".
+
+use bebytes::BeBytes;
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[derive(BeBytes, Debug, PartialEq)]
+#[bebytes(bit_order = "little")] // Error: expected "msb0" or "lsb0"
+struct Status {
+    #[bits(4)]
+    mode: u8,
+    #[bits(4)]
+    level: u8,
+}
+
+fn main() {}
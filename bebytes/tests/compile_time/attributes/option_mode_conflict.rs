@@ -0,0 +1,12 @@
+// This test verifies that an Option field uses a single presence encoding.
+
+use bebytes::BeBytes;
+
+#[derive(BeBytes)]
+struct Invalid {
+    has_port: bool,
+    #[bebytes(present_if = has_port, none_value = 0)]
+    port: Option<u16>, // Error: present_if and none_value together
+}
+
+fn main() {}
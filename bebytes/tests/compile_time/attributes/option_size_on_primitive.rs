@@ -0,0 +1,11 @@
+// This test verifies that size attributes are rejected on options of fixed-size types.
+
+use bebytes::BeBytes;
+
+#[derive(BeBytes)]
+struct Invalid {
+    #[With(size(4))]
+    value: Option<u32>, // Error: size attributes only apply to Option<Vec<..>> and Option<String>
+}
+
+fn main() {}
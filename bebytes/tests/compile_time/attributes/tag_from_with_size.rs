@@ -0,0 +1,20 @@
+// This test verifies that tag_from cannot be combined with
+// attributes that change how the field itself is sized.
+
+use bebytes::BeBytes;
+
+#[derive(BeBytes, Debug, PartialEq)]
+enum Body {
+    #[bebytes(tag = 1)]
+    Ping(u8),
+}
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct Packet {
+    kind: u8,
+    #[bebytes(tag_from = kind)]
+    #[With(size(4))]
+    body: Body, // Error: tag_from cannot be combined with size attributes
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+// This test verifies that #[bebytes(transparent)] is rejected on structs
+// that do not wrap exactly one field.
+
+use bebytes::BeBytes;
+
+#[derive(BeBytes)]
+#[bebytes(transparent)]
+struct NotANewtype(u16, u16);
+
+fn main() {}
@@ -0,0 +1,13 @@
+// This test verifies that word orders only apply to 32 and 64-bit values.
+
+use bebytes::BeBytes;
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct Holding {
+    #[bebytes(word_order = "CDAB")] // Error: a u16 is a single word
+    register: u16,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+// This test verifies that bit-field arrays count every element towards byte completeness.
+// Three 4-bit elements take 12 bits, which leaves the second byte incomplete.
+
+use bebytes::BeBytes;
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+// Error: bits attributes must complete a full byte
+#[derive(BeBytes, Debug, PartialEq)]
+struct Nibbles {
+    #[bits(4)]
+    digits: [u8; 3],
+}
+
+fn main() {}
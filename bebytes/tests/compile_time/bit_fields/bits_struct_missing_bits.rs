@@ -0,0 +1,15 @@
+// This test verifies that every field of a bits_struct needs #[bits(N)].
+
+use bebytes::BeBytes;
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[derive(BeBytes, Debug, PartialEq)]
+#[bebytes(bits_struct)]
+struct Tag {
+    #[bits(3)]
+    pcp: u8,
+    vid: u16, // Error: bits_struct fields are bit fields
+}
+
+fn main() {}
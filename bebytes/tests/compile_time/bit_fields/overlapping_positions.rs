@@ -0,0 +1,19 @@
+// This test verifies that positioned bit fields in the same group cannot share bits.
+// `mode` takes bits 10..14, which overlap the opcode's bits 12..16.
+
+use bebytes::BeBytes;
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct Register {
+    #[bits(12..16)]
+    opcode: u8,
+    // Error: bits 10..14 overlap bits 12..16 of opcode
+    #[bits(10..14)]
+    mode: u8,
+    #[bits(0..1)]
+    enable: bool,
+}
+
+fn main() {}
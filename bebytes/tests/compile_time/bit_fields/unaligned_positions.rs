@@ -0,0 +1,18 @@
+// This test verifies that a group of positioned bit fields starts on a byte boundary.
+// Bit positions are counted from the start of the group, so the 4 bits before it
+// cannot be shared with the group.
+
+use bebytes::BeBytes;
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct Register {
+    #[bits(4)]
+    version: u8,
+    // Error: the group starts in the middle of a byte
+    #[bits(0..4)]
+    kind: u8,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+// This test verifies that a constant discriminant cannot fall inside a range.
+
+use bebytes::BeBytes;
+
+const FIVE: u8 = 5;
+
+#[derive(BeBytes, Debug, PartialEq)]
+#[repr(u8)]
+enum Opt {
+    Five = FIVE, // Error: inside the range of Low
+    #[bebytes(range = 0..=10)]
+    Low(u8),
+}
+
+fn main() {}
@@ -0,0 +1,65 @@
+// This test verifies that derived enums can keep their own Display, FromStr, ALL
+// and name(): introspection is opt-in, and its trait impls can be left out.
+
+use bebytes::BeBytes;
+use core::fmt;
+use core::str::FromStr;
+
+#[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+#[bebytes(introspect, no_display, no_from_str)]
+enum Color {
+    Red = 1,
+    Green = 2,
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Color::Red => "red",
+            Color::Green => "green",
+        })
+    }
+}
+
+impl FromStr for Color {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "red" => Ok(Color::Red),
+            "green" => Ok(Color::Green),
+            _ => Err(()),
+        }
+    }
+}
+
+// Without `introspect`, nothing is generated that could clash
+#[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+enum Shape {
+    Circle = 1,
+    Square = 2,
+}
+
+impl Shape {
+    const ALL: [Shape; 2] = [Shape::Circle, Shape::Square];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Shape::Circle => "circle",
+            Shape::Square => "square",
+        }
+    }
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+fn main() {
+    assert_eq!(Color::Red.name(), "Red");
+    assert_eq!(Color::ALL, &[Color::Red, Color::Green]);
+    assert_eq!("green".parse::<Color>(), Ok(Color::Green));
+    assert_eq!(Shape::ALL[1].name(), "square");
+}
@@ -0,0 +1,14 @@
+// This test verifies that two variants of a data-carrying enum
+// cannot share the same tag.
+
+use bebytes::BeBytes;
+
+#[derive(BeBytes, Debug, PartialEq)]
+enum Message {
+    #[bebytes(tag = 1)]
+    Ping { seq: u32 },
+    #[bebytes(tag = 1)]
+    Pong { seq: u32 }, // Error: tag 1 is already used by Ping
+}
+
+fn main() {}
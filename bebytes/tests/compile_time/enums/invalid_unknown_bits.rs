@@ -0,0 +1,12 @@
+// This test verifies that the unknown bits policy of a flag set is validated.
+
+use bebytes::BeBytes;
+
+#[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+#[bebytes(flags, unknown_bits = "ignore")] // Error: not reject, keep or mask
+enum Permissions {
+    Read = 1,
+    Write = 2,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+// This test verifies that only one variant can catch unknown values.
+
+use bebytes::BeBytes;
+
+#[derive(BeBytes, Debug, PartialEq)]
+enum Opcode {
+    Read,
+    #[bebytes(other)]
+    Unknown(u8),
+    #[bebytes(other)]
+    Reserved(u8), // Error: second catch-all variant
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+// This test verifies that a catch-all variant must hold an unsigned
+// integer wide enough for every discriminant.
+
+use bebytes::BeBytes;
+
+#[derive(BeBytes, Debug, PartialEq)]
+#[repr(u16)]
+enum Opcode {
+    Read = 1,
+    Write = 300,
+    #[bebytes(other)]
+    Unknown(u8), // Error: 300 does not fit in u8
+}
+
+#[derive(BeBytes, Debug, PartialEq)]
+enum Status {
+    Ok,
+    #[bebytes(other)]
+    Unknown(String), // Error: must hold an unsigned integer
+}
+
+fn main() {}
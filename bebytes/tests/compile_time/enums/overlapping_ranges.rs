@@ -0,0 +1,16 @@
+// This test verifies that every value selects at most one variant.
+
+use bebytes::BeBytes;
+
+#[derive(BeBytes, Debug, PartialEq)]
+#[repr(u8)]
+enum OptionCode {
+    Pad = 0,
+    Special = 0xF5, // Error: inside the range of Vendor
+    #[bebytes(range = 0xF0..=0xFE)]
+    Vendor(u8),
+    #[bebytes(range = 0xE0..=0xF0)]
+    Experimental(u8), // Error: overlaps the range of Vendor
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+// This test verifies that an explicit tag type must be able to hold
+// every tag of a data-carrying enum.
+
+use bebytes::BeBytes;
+
+#[derive(BeBytes, Debug, PartialEq)]
+#[bebytes(tag_type = u8)]
+enum Message {
+    #[bebytes(tag = 1)]
+    Ping { seq: u32 },
+    #[bebytes(tag = 300)]
+    Pong { seq: u32 }, // Error: 300 does not fit in u8
+}
+
+fn main() {}
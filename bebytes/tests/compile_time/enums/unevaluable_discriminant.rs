@@ -0,0 +1,14 @@
+// This test verifies that a discriminant the macro cannot evaluate
+// requires a repr, so that its wire type is known.
+
+use bebytes::BeBytes;
+
+const SPECIAL: isize = 0x7F;
+
+#[derive(BeBytes, Debug, PartialEq)]
+enum Control {
+    Ack = 1,
+    Special = SPECIAL, // Error: no #[repr] to size the wire type
+}
+
+fn main() {}
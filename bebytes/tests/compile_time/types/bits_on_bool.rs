@@ -1,3 +1,5 @@
+// This test verifies that bool bit fields are limited to a single bit.
+
 use bebytes::BeBytes;
 #[cfg(not(feature = "std"))]
 extern crate alloc;
@@ -6,6 +8,7 @@ extern crate alloc;
 struct BoolWithBits {
     #[bits(1)]
     first: u8,
+    // Error: a bool flag holds exactly one bit
     #[bits(6)]
     second: bool,
     #[bits(1)]
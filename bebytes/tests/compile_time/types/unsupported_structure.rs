@@ -3,6 +3,6 @@ use bebytes::BeBytes;
 extern crate alloc;
 
 #[derive(BeBytes)]
-struct UnsupportedStruct(u8, u16, u32);
+struct UnsupportedStruct;
 
 fn main() {}
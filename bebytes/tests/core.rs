@@ -2,7 +2,7 @@
 //!
 //! This module tests basic serialization/deserialization of:
 //! - Primitive types
-//! - Arrays
+//! - Arrays, including arrays of multi-byte primitives, structs, enums and arrays
 //! - Basic structs
 //! - Nested structs
 
@@ -102,6 +102,185 @@ mod arrays {
     }
 }
 
+mod typed_arrays {
+    use super::*;
+    use bebytes::BeBytesError;
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy, Default)]
+    struct Point {
+        x: i16,
+        y: i16,
+    }
+
+    // Deliberately not `Copy` or `Default`
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Label {
+        len: u8,
+        #[FromField(len)]
+        text: Vec<u8>,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    enum Color {
+        Red = 1,
+        Green = 2,
+        Blue = 3,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct RegisterBank {
+        registers: [u32; 4],
+        status: u8,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Shape {
+        corners: [Point; 3],
+        palette: [Color; 2],
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Matrix {
+        rows: [[i16; 2]; 2],
+        scale: [f32; 1],
+        mask: [bool; 2],
+        glyphs: [char; 1],
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Labels {
+        labels: [Label; 2],
+    }
+
+    #[test]
+    fn test_primitive_array_endianness() {
+        let bank = RegisterBank {
+            registers: [1, 0x01020304, u32::MAX, 0],
+            status: 7,
+        };
+        assert_eq!(RegisterBank::field_size(), 17);
+
+        let bytes = bank.to_be_bytes();
+        assert_eq!(bytes[..8], [0, 0, 0, 1, 1, 2, 3, 4]);
+        let (decoded, consumed) = RegisterBank::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 17);
+        assert_eq!(decoded, bank);
+
+        let bytes = bank.to_le_bytes();
+        assert_eq!(bytes[..8], [1, 0, 0, 0, 4, 3, 2, 1]);
+        let (decoded, _) = RegisterBank::try_from_le_bytes(&bytes).unwrap();
+        assert_eq!(decoded, bank);
+    }
+
+    #[test]
+    fn test_struct_and_enum_arrays() {
+        let shape = Shape {
+            corners: [
+                Point { x: 1, y: -1 },
+                Point { x: 2, y: 3 },
+                Point::default(),
+            ],
+            palette: [Color::Blue, Color::Red],
+        };
+        assert_eq!(Shape::field_size(), 14);
+
+        let bytes = shape.to_be_bytes();
+        assert_eq!(bytes[..4], [0, 1, 0xFF, 0xFF]);
+        assert_eq!(bytes[12..], [3, 1]);
+        let (decoded, _) = Shape::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(decoded, shape);
+
+        let (decoded, _) = Shape::try_from_le_bytes(&shape.to_le_bytes()).unwrap();
+        assert_eq!(decoded, shape);
+
+        let mut invalid = bytes.clone();
+        invalid[13] = 9;
+        assert!(matches!(
+            Shape::try_from_be_bytes(&invalid),
+            Err(BeBytesError::InvalidDiscriminant { value: 9, .. })
+        ));
+    }
+
+    #[test]
+    fn test_nested_and_special_primitive_arrays() {
+        let matrix = Matrix {
+            rows: [[1, -2], [3, -4]],
+            scale: [0.5],
+            mask: [true, false],
+            glyphs: ['λ'],
+        };
+        assert_eq!(Matrix::field_size(), 8 + 4 + 2 + 4);
+
+        let bytes = matrix.to_be_bytes();
+        assert_eq!(bytes[..4], [0, 1, 0xFF, 0xFE]);
+        let (decoded, _) = Matrix::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(decoded, matrix);
+
+        let mut buf = bebytes::BytesMut::with_capacity(Matrix::field_size());
+        matrix.encode_le_to(&mut buf).unwrap();
+        assert_eq!(buf.to_vec(), matrix.to_le_bytes());
+    }
+
+    #[test]
+    fn test_variable_size_elements() {
+        let labels = Labels {
+            labels: [
+                Label {
+                    len: 2,
+                    text: b"hi".to_vec(),
+                },
+                Label {
+                    len: 0,
+                    text: Vec::new(),
+                },
+            ],
+        };
+
+        let bytes = labels.to_be_bytes();
+        assert_eq!(bytes, vec![2, b'h', b'i', 0]);
+        let (decoded, consumed) = Labels::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 4);
+        assert_eq!(decoded, labels);
+    }
+
+    #[test]
+    fn test_truncated_array() {
+        let result = RegisterBank::try_from_be_bytes(&[0, 0, 0, 1, 0, 0]);
+        assert!(matches!(result, Err(BeBytesError::InsufficientData { .. })));
+    }
+
+    #[test]
+    fn test_raw_pointer_encoding() {
+        assert!(RegisterBank::supports_raw_pointer_encoding());
+        assert_eq!(RegisterBank::RAW_POINTER_SIZE, 17);
+        let bank = RegisterBank {
+            registers: [1, 2, 3, 0x0A0B0C0D],
+            status: 9,
+        };
+        assert_eq!(bank.encode_be_to_raw_stack().to_vec(), bank.to_be_bytes());
+        assert_eq!(bank.encode_le_to_raw_stack().to_vec(), bank.to_le_bytes());
+
+        assert!(Matrix::supports_raw_pointer_encoding());
+        let matrix = Matrix {
+            rows: [[1, -2], [3, -4]],
+            scale: [1.25],
+            mask: [false, true],
+            glyphs: ['x'],
+        };
+        assert_eq!(
+            matrix.encode_be_to_raw_stack().to_vec(),
+            matrix.to_be_bytes()
+        );
+        assert_eq!(
+            matrix.encode_le_to_raw_stack().to_vec(),
+            matrix.to_le_bytes()
+        );
+
+        // Struct elements are only sized after monomorphization
+        assert!(!Shape::supports_raw_pointer_encoding());
+    }
+}
+
 mod basic_structs {
     use super::*;
 
@@ -0,0 +1,232 @@
+//! Endianness override tests for BeBytes
+//!
+//! This module tests:
+//! - Fields with a fixed byte order through `#[bebytes(endian = "..")]`
+//! - Nested structs, vectors and options with a fixed byte order
+//! - The `big_endian` and `little_endian` shorthands
+//! - Raw pointer encoding of structs with fixed-order fields
+//! - Struct-level `#[bebytes(endian = "..")]` with `to_bytes` and `try_from_bytes`
+//! - Byte order picked at runtime with `bebytes::Endian`
+
+use bebytes::{BeBytes, BytesMut, Endian};
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct CaptureHeader {
+    #[bebytes(endian = "big")]
+    magic: u32,
+    version: u16,
+    snap_len: u32,
+}
+
+#[derive(BeBytes, Debug, PartialEq, Clone)]
+struct Payload {
+    kind: u16,
+    value: i32,
+}
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct Envelope {
+    length: u16,
+    #[bebytes(endian = "little")]
+    payload: Payload,
+    #[bebytes(endian = "little")]
+    checksum: Option<u32>,
+    #[bebytes(endian = "little")]
+    #[With(size(2))]
+    samples: Vec<u16>,
+}
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct Shorthand {
+    #[bebytes(little_endian)]
+    low: u16,
+    #[bebytes(big_endian)]
+    high: u16,
+}
+
+#[derive(BeBytes, Debug, PartialEq)]
+#[bebytes(endian = "little")]
+struct GameRecord {
+    id: u32,
+    score: i16,
+    #[bebytes(endian = "big")]
+    tag: u16,
+}
+
+#[test]
+fn test_fixed_field_ignores_method() {
+    let header = CaptureHeader {
+        magic: 0xA1B2_C3D4,
+        version: 2,
+        snap_len: 0xFFFF,
+    };
+
+    let le_bytes = header.to_le_bytes();
+    assert_eq!(
+        le_bytes,
+        vec![0xA1, 0xB2, 0xC3, 0xD4, 0x02, 0x00, 0xFF, 0xFF, 0x00, 0x00]
+    );
+    let (decoded, consumed) = CaptureHeader::try_from_le_bytes(&le_bytes).unwrap();
+    assert_eq!(consumed, 10);
+    assert_eq!(decoded, header);
+
+    let be_bytes = header.to_be_bytes();
+    assert_eq!(&be_bytes[..4], &[0xA1, 0xB2, 0xC3, 0xD4]);
+    assert_eq!(&be_bytes[4..6], &[0x00, 0x02]);
+    let (decoded, _) = CaptureHeader::try_from_be_bytes(&be_bytes).unwrap();
+    assert_eq!(decoded, header);
+}
+
+#[test]
+fn test_nested_struct_option_and_vector() {
+    let envelope = Envelope {
+        length: 0x0102,
+        payload: Payload {
+            kind: 0x0304,
+            value: -2,
+        },
+        checksum: Some(0x0A0B_0C0D),
+        samples: vec![0x1122, 0x3344],
+    };
+
+    let bytes = envelope.to_be_bytes();
+    assert_eq!(
+        bytes,
+        vec![
+            0x01, 0x02, // length follows the method
+            0x04, 0x03, 0xFE, 0xFF, 0xFF, 0xFF, // payload is little-endian throughout
+            0x01, 0x0D, 0x0C, 0x0B, 0x0A, // option tag, then the value
+            0x22, 0x11, 0x44, 0x33,
+        ]
+    );
+    let (decoded, _) = Envelope::try_from_be_bytes(&bytes).unwrap();
+    assert_eq!(decoded, envelope);
+
+    let le_bytes = envelope.to_le_bytes();
+    assert_eq!(&le_bytes[..2], &[0x02, 0x01]);
+    assert_eq!(&le_bytes[2..], &bytes[2..]);
+    let (decoded, _) = Envelope::try_from_le_bytes(&le_bytes).unwrap();
+    assert_eq!(decoded, envelope);
+}
+
+#[test]
+fn test_shorthands() {
+    let value = Shorthand {
+        low: 0x1234,
+        high: 0x5678,
+    };
+
+    let expected = vec![0x34, 0x12, 0x56, 0x78];
+    assert_eq!(value.to_be_bytes(), expected);
+    assert_eq!(value.to_le_bytes(), expected);
+    let (decoded, _) = Shorthand::try_from_be_bytes(&expected).unwrap();
+    assert_eq!(decoded, value);
+}
+
+#[test]
+fn test_raw_pointer_encoding_keeps_field_order() {
+    let value = Shorthand {
+        low: 0x1234,
+        high: 0x5678,
+    };
+
+    assert!(Shorthand::supports_raw_pointer_encoding());
+    assert_eq!(value.encode_be_to_raw_stack().to_vec(), value.to_be_bytes());
+    assert_eq!(value.encode_le_to_raw_stack().to_vec(), value.to_le_bytes());
+}
+
+#[test]
+fn test_struct_default_endianness() {
+    let record = GameRecord {
+        id: 7,
+        score: -1,
+        tag: 0xCAFE,
+    };
+
+    let bytes = record.to_bytes();
+    assert_eq!(bytes, record.to_le_bytes());
+    assert_eq!(bytes, vec![0x07, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xCA, 0xFE]);
+
+    let (decoded, consumed) = GameRecord::try_from_bytes(&bytes).unwrap();
+    assert_eq!(consumed, 8);
+    assert_eq!(decoded, record);
+}
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct PcapHeader {
+    magic: u32,
+    version_major: u16,
+    version_minor: u16,
+    snap_len: u32,
+}
+
+#[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+enum LinkType {
+    Ethernet = 1,
+    Raw = 0x0101,
+}
+
+/// Reads a pcap header in whichever byte order its magic number was written
+fn read_pcap_header(bytes: &[u8]) -> PcapHeader {
+    let endian = match bytes[..4] {
+        [0xA1, 0xB2, 0xC3, 0xD4] => Endian::Big,
+        [0xD4, 0xC3, 0xB2, 0xA1] => Endian::Little,
+        _ => panic!("not a pcap file"),
+    };
+    PcapHeader::try_from_bytes_with(bytes, endian).unwrap().0
+}
+
+#[test]
+fn test_runtime_byte_order() {
+    let header = PcapHeader {
+        magic: 0xA1B2_C3D4,
+        version_major: 2,
+        version_minor: 4,
+        snap_len: 65535,
+    };
+
+    for endian in [Endian::Big, Endian::Little] {
+        let bytes = header.to_bytes_with(endian);
+        assert_eq!(read_pcap_header(&bytes), header);
+    }
+    assert_eq!(header.to_bytes_with(Endian::Big), header.to_be_bytes());
+    assert_eq!(header.to_bytes_with(Endian::Little), header.to_le_bytes());
+}
+
+#[test]
+fn test_runtime_byte_order_for_enums() {
+    let bytes = LinkType::Ethernet.to_bytes_with(Endian::Little);
+    assert_eq!(bytes, vec![0x01, 0x00]);
+    let (decoded, consumed) = LinkType::try_from_bytes_with(&bytes, Endian::Little).unwrap();
+    assert_eq!(consumed, 2);
+    assert_eq!(decoded, LinkType::Ethernet);
+
+    let (decoded, _) = LinkType::try_from_bytes_with(&[0, 1], Endian::Big).unwrap();
+    assert_eq!(decoded, LinkType::Ethernet);
+    let (decoded, _) = LinkType::try_from_bytes_with(&[1, 1], Endian::Little).unwrap();
+    assert_eq!(decoded, LinkType::Raw);
+}
+
+#[test]
+fn test_encode_to_with() {
+    let header = PcapHeader {
+        magic: 0xA1B2_C3D4,
+        version_major: 2,
+        version_minor: 4,
+        snap_len: 65535,
+    };
+
+    let mut buf = BytesMut::with_capacity(PcapHeader::field_size());
+    header.encode_to_with(&mut buf, Endian::Little).unwrap();
+    assert_eq!(buf.to_vec(), header.to_le_bytes());
+}
+
+#[test]
+fn test_native_endian() {
+    let expected = if cfg!(target_endian = "big") {
+        Endian::Big
+    } else {
+        Endian::Little
+    };
+    assert_eq!(Endian::NATIVE, expected);
+}
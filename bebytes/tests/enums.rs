@@ -5,6 +5,11 @@
 //! - Bit fields with explicit sizes
 //! - Flag enums with bitwise operations
 //! - Bit packing optimization
+//! - Catch-all variants for unknown discriminants
+//! - Negative, constant, byte and char discriminants
+//! - Discriminant ranges held by a single variant
+//! - Flag-set types generated for flag enums
+//! - Variant names, `ALL`, `FromStr` and `Display`
 
 use bebytes::BeBytes;
 
@@ -354,6 +359,7 @@ mod multibyte_flags {
         Bit15 = 32768,
     }
 
+    #[allow(clippy::enum_clike_unportable_variant)]
     #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
     #[bebytes(flags(u32))]
     enum ExplicitU32Flags {
@@ -499,6 +505,7 @@ mod multibyte_flags {
         assert!(flags.contains(&U16Flags::Bit15));
     }
 
+    #[allow(clippy::enum_clike_unportable_variant)]
     #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
     #[bebytes(flags)]
     enum U64Flags {
@@ -558,6 +565,7 @@ mod multibyte_flags {
         assert!(decomposed.contains(&U64Flags::Bit62));
     }
 
+    #[allow(clippy::enum_clike_unportable_variant)]
     #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
     #[bebytes(flags(u128))]
     enum U128Flags {
@@ -779,3 +787,521 @@ mod enum_bit_packing {
         assert_eq!(decoded.value, 10);
     }
 }
+
+mod catch_all_variants {
+    use super::*;
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    #[repr(u8)]
+    enum Opcode {
+        Read = 1,
+        Write = 2,
+        #[bebytes(other)]
+        Unknown(u8),
+    }
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    enum Command {
+        Start,
+        Stop,
+        #[bebytes(other)]
+        Unknown(u16),
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Request {
+        opcode: Opcode,
+        length: u16,
+    }
+
+    #[test]
+    fn test_known_values_still_decode() {
+        assert_eq!(Opcode::try_from_be_bytes(&[2]).unwrap(), (Opcode::Write, 1));
+        assert_eq!(Opcode::Read.to_be_bytes(), vec![1]);
+        assert_eq!(Opcode::try_from(1u8).unwrap(), Opcode::Read);
+    }
+
+    #[test]
+    fn test_unknown_value_round_trip() {
+        let (decoded, consumed) = Opcode::try_from_be_bytes(&[0x7F]).unwrap();
+        assert_eq!(consumed, 1);
+        assert_eq!(decoded, Opcode::Unknown(0x7F));
+        assert_eq!(decoded.to_be_bytes(), vec![0x7F]);
+        assert_eq!(Opcode::try_from(0u8).unwrap(), Opcode::Unknown(0));
+    }
+
+    #[test]
+    fn test_other_type_sets_width() {
+        assert_eq!(Command::field_size(), 2);
+        assert_eq!(Command::Stop.to_be_bytes(), vec![0, 1]);
+
+        let (decoded, consumed) = Command::try_from_be_bytes(&[0x12, 0x34]).unwrap();
+        assert_eq!(consumed, 2);
+        assert_eq!(decoded, Command::Unknown(0x1234));
+        assert_eq!(decoded.to_be_bytes(), vec![0x12, 0x34]);
+
+        let (decoded, _) = Command::try_from_le_bytes(&[0x34, 0x12]).unwrap();
+        assert_eq!(decoded, Command::Unknown(0x1234));
+        assert_eq!(decoded.to_le_bytes(), vec![0x34, 0x12]);
+        assert_eq!(
+            Command::try_from(0x1234u16).unwrap(),
+            Command::Unknown(0x1234)
+        );
+    }
+
+    #[test]
+    fn test_unknown_value_passes_through_struct() {
+        let bytes = [0xF0, 0x00, 0x05];
+        let (request, _) = Request::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(request.opcode, Opcode::Unknown(0xF0));
+        assert_eq!(request.length, 5);
+        assert_eq!(request.to_be_bytes(), bytes.to_vec());
+    }
+}
+
+mod discriminant_expressions {
+    use super::*;
+
+    mod codes {
+        pub const SPECIAL: u8 = 0x7F;
+    }
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    #[repr(u8)]
+    enum Control {
+        Ack = b'A',
+        Nak = b'N',
+        Special = codes::SPECIAL,
+        AfterSpecial,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    enum Reply {
+        Yes = 'Y' as isize,
+        No = 'N' as isize,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    #[repr(i8)]
+    enum Status {
+        Err = -1,
+        Ok = 0,
+        Pending,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    #[repr(i16)]
+    enum Level {
+        Low = -300,
+        Zero = 0,
+        High = 300,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    enum Shifted {
+        First = 1 << 2,
+        Second,
+        Third = 0x10 | 0x01,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Frame {
+        control: Control,
+        status: Status,
+    }
+
+    #[test]
+    fn test_byte_and_char_literals() {
+        assert_eq!(Control::Ack.to_be_bytes(), vec![b'A']);
+        assert_eq!(Control::Nak.to_be_bytes(), vec![b'N']);
+        assert_eq!(Reply::Yes.to_be_bytes(), vec![b'Y']);
+        assert_eq!(Reply::try_from_be_bytes(b"N").unwrap(), (Reply::No, 1));
+    }
+
+    #[test]
+    fn test_constant_discriminant() {
+        assert_eq!(Control::field_size(), 1);
+        assert_eq!(Control::Special.to_be_bytes(), vec![0x7F]);
+        assert_eq!(Control::AfterSpecial.to_be_bytes(), vec![0x80]);
+        assert_eq!(Control::try_from(0x80u8).unwrap(), Control::AfterSpecial);
+        assert_eq!(
+            Control::try_from_be_bytes(&[0x7F]).unwrap(),
+            (Control::Special, 1)
+        );
+        assert!(Control::try_from(0x81u8).is_err());
+    }
+
+    #[test]
+    fn test_implicit_values_follow_previous_discriminant() {
+        assert_eq!(Shifted::First.to_be_bytes(), vec![4]);
+        assert_eq!(Shifted::Second.to_be_bytes(), vec![5]);
+        assert_eq!(Shifted::Third.to_be_bytes(), vec![0x11]);
+        assert_eq!(Status::Pending.to_be_bytes(), vec![1]);
+    }
+
+    #[test]
+    fn test_signed_round_trip() {
+        assert_eq!(Status::field_size(), 1);
+        assert_eq!(Status::Err.to_be_bytes(), vec![0xFF]);
+        assert_eq!(
+            Status::try_from_be_bytes(&[0xFF]).unwrap(),
+            (Status::Err, 1)
+        );
+        assert_eq!(Status::try_from(-1i8).unwrap(), Status::Err);
+
+        assert_eq!(Level::field_size(), 2);
+        assert_eq!(Level::Low.to_be_bytes(), (-300i16).to_be_bytes().to_vec());
+        assert_eq!(Level::Low.to_le_bytes(), (-300i16).to_le_bytes().to_vec());
+        for level in [Level::Low, Level::Zero, Level::High] {
+            let (decoded, _) = Level::try_from_be_bytes(&level.to_be_bytes()).unwrap();
+            assert_eq!(decoded, level);
+            let (decoded, _) = Level::try_from_le_bytes(&level.to_le_bytes()).unwrap();
+            assert_eq!(decoded, level);
+        }
+    }
+
+    #[test]
+    fn test_signed_unknown_value_reports_wire_byte() {
+        match Status::try_from_be_bytes(&[0xFE]) {
+            Err(bebytes::BeBytesError::InvalidDiscriminant { value, type_name }) => {
+                assert_eq!(value, 0xFE);
+                assert_eq!(type_name, "Status");
+            }
+            other => panic!("Expected InvalidDiscriminant, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_expression_enums_in_struct() {
+        let frame = Frame {
+            control: Control::Special,
+            status: Status::Err,
+        };
+        let bytes = frame.to_be_bytes();
+        assert_eq!(bytes, vec![0x7F, 0xFF]);
+        assert_eq!(Frame::try_from_be_bytes(&bytes).unwrap(), (frame, 2));
+    }
+}
+
+mod discriminant_ranges {
+    use super::*;
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    #[repr(u8)]
+    enum OptionCode {
+        Pad = 0,
+        #[bebytes(range = 0xE0..0xF0)]
+        Experimental(u8),
+        #[bebytes(range = 0xF0..=0xFE)]
+        Vendor(u8),
+        #[bebytes(other)]
+        Unassigned(u8),
+        End = 255,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    enum Port {
+        Unset,
+        #[bebytes(range = 1..=1023)]
+        WellKnown(u16),
+        #[bebytes(range = 1024..=0xFFFF)]
+        Registered(u16),
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct TlvHeader {
+        code: OptionCode,
+        length: u8,
+    }
+
+    const FIVE: u8 = 5;
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    #[repr(u8)]
+    enum Level {
+        Five = FIVE,
+        #[bebytes(range = 10..=20)]
+        High(u8),
+    }
+
+    #[test]
+    fn test_range_keeps_raw_value() {
+        assert_eq!(
+            OptionCode::try_from_be_bytes(&[0xF3]).unwrap(),
+            (OptionCode::Vendor(0xF3), 1)
+        );
+        assert_eq!(OptionCode::Vendor(0xF3).to_be_bytes(), vec![0xF3]);
+        assert_eq!(
+            OptionCode::try_from(0xFEu8).unwrap(),
+            OptionCode::Vendor(0xFE)
+        );
+    }
+
+    #[test]
+    fn test_range_bounds() {
+        assert_eq!(
+            OptionCode::try_from(0xE0u8).unwrap(),
+            OptionCode::Experimental(0xE0)
+        );
+        assert_eq!(
+            OptionCode::try_from(0xEFu8).unwrap(),
+            OptionCode::Experimental(0xEF)
+        );
+        assert_eq!(
+            OptionCode::try_from(0xF0u8).unwrap(),
+            OptionCode::Vendor(0xF0)
+        );
+        assert_eq!(OptionCode::try_from(0xFFu8).unwrap(), OptionCode::End);
+        assert_eq!(
+            OptionCode::try_from(0xDFu8).unwrap(),
+            OptionCode::Unassigned(0xDF)
+        );
+    }
+
+    #[test]
+    fn test_ranges_without_catch_all() {
+        assert_eq!(Port::field_size(), 2);
+        assert_eq!(Port::try_from(0u16).unwrap(), Port::Unset);
+        assert_eq!(Port::try_from(80u16).unwrap(), Port::WellKnown(80));
+        assert_eq!(Port::try_from(8080u16).unwrap(), Port::Registered(8080));
+
+        let (decoded, _) = Port::try_from_le_bytes(&[0x50, 0x00]).unwrap();
+        assert_eq!(decoded, Port::WellKnown(80));
+        assert_eq!(Port::Registered(0x1F90).to_be_bytes(), vec![0x1F, 0x90]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Value 5000 is outside the range 1..=1023 for variant WellKnown")]
+    fn test_out_of_range_value_panics_on_encode() {
+        let _ = Port::WellKnown(5000).to_be_bytes();
+    }
+
+    #[test]
+    fn test_range_next_to_constant_discriminant() {
+        assert_eq!(Level::Five.to_be_bytes(), vec![5]);
+        assert_eq!(Level::High(12).to_le_bytes(), vec![12]);
+        assert_eq!(Level::try_from(12u8).unwrap(), Level::High(12));
+        assert_eq!(Level::try_from(5u8).unwrap(), Level::Five);
+    }
+
+    #[test]
+    fn test_range_variant_in_struct() {
+        let header = TlvHeader {
+            code: OptionCode::Experimental(0xE5),
+            length: 4,
+        };
+        let bytes = header.to_be_bytes();
+        assert_eq!(bytes, vec![0xE5, 4]);
+        assert_eq!(TlvHeader::try_from_be_bytes(&bytes).unwrap(), (header, 2));
+    }
+}
+
+mod flag_sets {
+    use super::*;
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    #[bebytes(flags)]
+    enum Permissions {
+        None = 0,
+        Read = 1,
+        Write = 2,
+        Execute = 4,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    #[bebytes(flags, unknown_bits = "keep")]
+    enum Capabilities {
+        Tls = 1,
+        Compression = 1 << 8,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    #[bebytes(flags, unknown_bits = "mask")]
+    enum Options {
+        A = 1,
+        B = 2,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct FileEntry {
+        id: u16,
+        permissions: PermissionsSet,
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let mut set = PermissionsSet::from(Permissions::Read) | Permissions::Write;
+        assert!(set.contains(Permissions::Read));
+        assert!(set.contains(Permissions::Write));
+        assert!(!set.contains(Permissions::Execute));
+        assert_eq!(set.bits(), 3);
+
+        set.insert(Permissions::Execute);
+        set.remove(Permissions::Read);
+        assert_eq!(set.bits(), 6);
+        set.toggle(Permissions::Write);
+        assert_eq!(set, PermissionsSet::from(Permissions::Execute));
+
+        assert!(PermissionsSet::empty().is_empty());
+        assert_eq!(PermissionsSet::all().bits(), 7);
+        assert_eq!(
+            PermissionsSet::all() - set,
+            PermissionsSet::from_bits_retain(3)
+        );
+    }
+
+    #[test]
+    fn test_iteration_and_collect() {
+        let set: PermissionsSet = [Permissions::Execute, Permissions::Read]
+            .into_iter()
+            .collect();
+        let flags = set.iter().collect::<Vec<_>>();
+        assert_eq!(flags, vec![Permissions::Read, Permissions::Execute]);
+        assert_eq!(PermissionsSet::empty().iter().count(), 0);
+    }
+
+    #[test]
+    fn test_debug_shows_flag_names() {
+        let set = PermissionsSet::from(Permissions::Read) | Permissions::Execute;
+        assert_eq!(format!("{set:?}"), "PermissionsSet(Read | Execute)");
+        assert_eq!(
+            format!("{:?}", PermissionsSet::empty()),
+            "PermissionsSet(empty)"
+        );
+        assert_eq!(
+            format!("{:?}", PermissionsSet::from_bits_retain(0x11)),
+            "PermissionsSet(Read | 0x10)"
+        );
+    }
+
+    #[test]
+    fn test_set_as_struct_field() {
+        let entry = FileEntry {
+            id: 7,
+            permissions: PermissionsSet::from(Permissions::Read) | Permissions::Write,
+        };
+        let bytes = entry.to_be_bytes();
+        assert_eq!(bytes, vec![0, 7, 3]);
+        assert_eq!(FileEntry::try_from_be_bytes(&bytes).unwrap(), (entry, 3));
+    }
+
+    #[test]
+    fn test_unknown_bits_rejected_by_default() {
+        assert_eq!(PermissionsSet::from_bits(0x08), None);
+        match PermissionsSet::try_from_be_bytes(&[0x09]) {
+            Err(bebytes::BeBytesError::InvalidDiscriminant { value, type_name }) => {
+                assert_eq!(value, 0x09);
+                assert_eq!(type_name, "PermissionsSet");
+            }
+            other => panic!("Expected InvalidDiscriminant, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_unknown_bits_kept() {
+        assert_eq!(CapabilitiesSet::field_size(), 2);
+        let (set, consumed) = CapabilitiesSet::try_from_be_bytes(&[0x81, 0x01]).unwrap();
+        assert_eq!(consumed, 2);
+        assert!(set.contains(Capabilities::Tls));
+        assert!(set.contains(Capabilities::Compression));
+        assert_eq!(set.bits(), 0x8101);
+        assert_eq!(set.to_be_bytes(), vec![0x81, 0x01]);
+        assert_eq!(set.to_le_bytes(), vec![0x01, 0x81]);
+    }
+
+    #[test]
+    fn test_unknown_bits_masked() {
+        let (set, _) = OptionsSet::try_from_le_bytes(&[0xFF]).unwrap();
+        assert_eq!(set, OptionsSet::all());
+        assert_eq!(set.to_be_bytes(), vec![0x03]);
+    }
+}
+
+mod introspection {
+    use super::*;
+    use bebytes::BeBytesError;
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    #[bebytes(introspect)]
+    enum State {
+        Idle = 0,
+        Running = 4,
+        Stopped,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    #[bebytes(introspect)]
+    #[repr(u8)]
+    enum Opcode {
+        Read = 1,
+        Write = 2,
+        #[bebytes(range = 0xF0..=0xFF)]
+        Vendor(u8),
+        #[bebytes(other)]
+        Unknown(u8),
+    }
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    #[bebytes(flags, introspect)]
+    enum Permissions {
+        Read = 1,
+        Write = 2,
+    }
+
+    // A variant named `Err` must not clash with `FromStr::Err`
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    #[bebytes(introspect)]
+    enum Outcome {
+        Ok = 0,
+        Err = 1,
+    }
+
+    #[test]
+    fn test_all_lists_unit_variants_in_order() {
+        assert_eq!(State::ALL, &[State::Idle, State::Running, State::Stopped]);
+        assert_eq!(Opcode::ALL, &[Opcode::Read, Opcode::Write]);
+        assert_eq!(Permissions::ALL, &[Permissions::Read, Permissions::Write]);
+    }
+
+    #[test]
+    fn test_name() {
+        assert_eq!(State::Running.name(), "Running");
+        assert_eq!(Opcode::Vendor(0xF1).name(), "Vendor");
+        assert_eq!(Opcode::Unknown(9).name(), "Unknown");
+
+        const NAME: &str = State::Stopped.name();
+        assert_eq!(NAME, "Stopped");
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(State::Idle.to_string(), "Idle");
+        assert_eq!(format!("{}", Opcode::Unknown(9)), "Unknown");
+        assert_eq!(Outcome::Err.to_string(), "Err");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("Running".parse::<State>(), Ok(State::Running));
+        assert_eq!("Write".parse::<Opcode>(), Ok(Opcode::Write));
+        assert_eq!("Err".parse::<Outcome>(), Ok(Outcome::Err));
+        assert_eq!(
+            "running".parse::<State>(),
+            Err(BeBytesError::InvalidVariantName { type_name: "State" })
+        );
+        // Variants holding a raw value can't be built from their name alone
+        assert_eq!(
+            "Unknown".parse::<Opcode>(),
+            Err(BeBytesError::InvalidVariantName {
+                type_name: "Opcode"
+            })
+        );
+    }
+
+    #[test]
+    fn test_names_round_trip() {
+        for state in State::ALL {
+            assert_eq!(state.name().parse::<State>(), Ok(*state));
+        }
+    }
+}
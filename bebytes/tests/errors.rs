@@ -41,6 +41,28 @@ mod error_display {
             err.to_string(),
             "Value 256 exceeds maximum 255 for field test_field"
         );
+
+        // InvalidSignedBitField
+        let err = BeBytesError::InvalidSignedBitField {
+            value: -17,
+            max: 15,
+            field: "offset",
+        };
+        assert_eq!(
+            err.to_string(),
+            "Value -17 is outside the range -16..=15 for field offset"
+        );
+
+        // InvalidByteLength
+        let err = BeBytesError::InvalidByteLength {
+            length: 5,
+            element_size: 2,
+            field: "offsets",
+        };
+        assert_eq!(
+            err.to_string(),
+            "Byte length 5 of field 'offsets' is not a multiple of its element size 2"
+        );
     }
 
     #[test]
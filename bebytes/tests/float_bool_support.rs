@@ -23,6 +23,7 @@ struct MixedStruct {
 }
 
 #[test]
+#[allow(clippy::approx_constant)]
 fn test_f32_round_trip() {
     let values = [0.0f32, 1.0, -1.0, 3.14159, f32::MAX, f32::MIN, f32::EPSILON];
 
@@ -38,6 +39,7 @@ fn test_f32_round_trip() {
 }
 
 #[test]
+#[allow(clippy::approx_constant)]
 fn test_f64_round_trip() {
     let values = [
         0.0f64,
@@ -0,0 +1,313 @@
+//! Generic struct tests for BeBytes
+//!
+//! This module tests:
+//! - Type parameters used as fields, with the `BeBytes` bound inferred
+//! - Vectors of a generic element type
+//! - Const generic array lengths
+//! - Lifetime parameters held by zero-size `PhantomData` fields
+//! - Generic `#[bebytes(transparent)]` newtypes
+//! - Raw pointer helpers on generic structs
+
+use bebytes::BeBytes;
+
+mod type_parameters {
+    use super::*;
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    struct Header {
+        version: u8,
+        kind: u8,
+    }
+
+    // Deliberately not `Clone`: nested types are encoded by reference
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Ping {
+        sequence: u32,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Frame<T> {
+        header: Header,
+        body: T,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Envelope<A, B>
+    where
+        B: core::fmt::Debug,
+    {
+        first: A,
+        checksum: u8,
+        second: B,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Batch<T> {
+        count: u8,
+        #[FromField(count)]
+        items: Vec<T>,
+    }
+
+    #[test]
+    fn test_generic_payload_round_trip() {
+        let frame = Frame {
+            header: Header {
+                version: 1,
+                kind: 2,
+            },
+            body: Ping {
+                sequence: 0x01020304,
+            },
+        };
+        assert_eq!(Frame::<Ping>::field_size(), 6);
+
+        let be_bytes = frame.to_be_bytes();
+        assert_eq!(be_bytes, vec![1, 2, 1, 2, 3, 4]);
+        let (decoded, consumed) = Frame::<Ping>::try_from_be_bytes(&be_bytes).unwrap();
+        assert_eq!(consumed, 6);
+        assert_eq!(decoded, frame);
+
+        let le_bytes = frame.to_le_bytes();
+        assert_eq!(le_bytes, vec![1, 2, 4, 3, 2, 1]);
+        let (decoded, _) = Frame::<Ping>::try_from_le_bytes(&le_bytes).unwrap();
+        assert_eq!(decoded, frame);
+    }
+
+    #[test]
+    fn test_nested_generic_instantiation() {
+        let frame = Frame {
+            header: Header {
+                version: 3,
+                kind: 4,
+            },
+            body: Frame {
+                header: Header {
+                    version: 5,
+                    kind: 6,
+                },
+                body: Ping { sequence: 7 },
+            },
+        };
+
+        let bytes = frame.to_be_bytes();
+        assert_eq!(bytes, vec![3, 4, 5, 6, 0, 0, 0, 7]);
+        let (decoded, _) = Frame::<Frame<Ping>>::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(decoded, frame);
+    }
+
+    #[test]
+    fn test_multiple_parameters_with_where_clause() {
+        let envelope = Envelope {
+            first: Ping { sequence: 1 },
+            checksum: 0xCC,
+            second: Header {
+                version: 9,
+                kind: 8,
+            },
+        };
+
+        let bytes = envelope.to_be_bytes();
+        assert_eq!(bytes, vec![0, 0, 0, 1, 0xCC, 9, 8]);
+        let (decoded, _) = Envelope::<Ping, Header>::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(decoded, envelope);
+    }
+
+    #[test]
+    fn test_vector_of_generic_elements() {
+        let batch = Batch {
+            count: 2,
+            items: vec![Ping { sequence: 1 }, Ping { sequence: 2 }],
+        };
+
+        let bytes = batch.to_be_bytes();
+        assert_eq!(bytes, vec![2, 0, 0, 0, 1, 0, 0, 0, 2]);
+        let (decoded, consumed) = Batch::<Ping>::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 9);
+        assert_eq!(decoded, batch);
+    }
+
+    #[test]
+    fn test_generic_constructor() {
+        let frame = Frame::new(
+            Header {
+                version: 1,
+                kind: 1,
+            },
+            Ping { sequence: 42 },
+        );
+        assert_eq!(frame.body.sequence, 42);
+    }
+}
+
+mod const_generics {
+    use super::*;
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Block<const N: usize> {
+        id: u8,
+        data: [u8; N],
+        crc: u16,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Table<const N: usize> {
+        values: [u16; N],
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Tagged<const TAG: u8> {
+        value: u16,
+        raw: [u8; 2],
+    }
+
+    #[test]
+    fn test_const_length_round_trip() {
+        let block = Block::<4> {
+            id: 1,
+            data: [0xA, 0xB, 0xC, 0xD],
+            crc: 0xBEEF,
+        };
+        assert_eq!(Block::<4>::field_size(), 7);
+        assert_eq!(Block::<16>::field_size(), 19);
+
+        let bytes = block.to_be_bytes();
+        assert_eq!(bytes, vec![1, 0xA, 0xB, 0xC, 0xD, 0xBE, 0xEF]);
+        let (decoded, consumed) = Block::<4>::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 7);
+        assert_eq!(decoded, block);
+
+        let le_bytes = block.to_le_bytes();
+        assert_eq!(le_bytes, vec![1, 0xA, 0xB, 0xC, 0xD, 0xEF, 0xBE]);
+    }
+
+    #[test]
+    fn test_const_length_typed_array() {
+        let table = Table::<3> {
+            values: [1, 2, 0x0304],
+        };
+        assert_eq!(Table::<3>::field_size(), 6);
+
+        let bytes = table.to_le_bytes();
+        assert_eq!(bytes, vec![1, 0, 2, 0, 4, 3]);
+        let (decoded, _) = Table::<3>::try_from_le_bytes(&bytes).unwrap();
+        assert_eq!(decoded, table);
+    }
+
+    #[test]
+    fn test_const_length_encode_to_buffer() {
+        let block = Block::<2>::new(7, [1, 2], 3);
+        let mut buf = bebytes::BytesMut::with_capacity(Block::<2>::field_size());
+        block.encode_be_to(&mut buf).unwrap();
+        assert_eq!(buf.to_vec(), vec![7, 1, 2, 0, 3]);
+    }
+
+    #[test]
+    fn test_raw_pointer_helpers() {
+        // A const generic length is only known after monomorphization
+        assert!(!Block::<4>::supports_raw_pointer_encoding());
+
+        // Fixed-size layouts keep their raw pointer helpers
+        assert!(Tagged::<1>::supports_raw_pointer_encoding());
+        assert_eq!(Tagged::<1>::RAW_POINTER_SIZE, 4);
+        let tagged = Tagged::<1> {
+            value: 0x0102,
+            raw: [3, 4],
+        };
+        assert_eq!(tagged.encode_be_to_raw_stack(), [1, 2, 3, 4]);
+    }
+}
+
+mod lifetimes {
+    use super::*;
+    use core::marker::PhantomData;
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Reading {
+        value: u16,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Borrowed<'a, T: 'a> {
+        id: u8,
+        body: T,
+        _marker: PhantomData<&'a ()>,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    enum Event<'a> {
+        Reading(u8, PhantomData<&'a ()>),
+        Reset { code: u16 },
+    }
+
+    #[test]
+    fn test_lifetime_round_trip() {
+        let frame = Borrowed {
+            id: 7,
+            body: Reading { value: 0x0102 },
+            _marker: PhantomData,
+        };
+        assert_eq!(Borrowed::<Reading>::field_size(), 3);
+
+        let bytes = frame.to_be_bytes();
+        assert_eq!(bytes, vec![7, 0x01, 0x02]);
+        let (decoded, consumed) = Borrowed::<Reading>::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 3);
+        assert_eq!(decoded, frame);
+
+        let le_bytes = frame.to_le_bytes();
+        assert_eq!(le_bytes, vec![7, 0x02, 0x01]);
+        let (decoded, _) = Borrowed::<Reading>::try_from_le_bytes(&le_bytes).unwrap();
+        assert_eq!(decoded, frame);
+
+        let mut buf = bebytes::BytesMut::with_capacity(Borrowed::<Reading>::field_size());
+        frame.encode_be_to(&mut buf).unwrap();
+        assert_eq!(buf.to_vec(), bytes);
+    }
+
+    #[test]
+    fn test_lifetime_enum_variant() {
+        let event = Event::Reading(9, PhantomData);
+        let bytes = event.to_be_bytes();
+        assert_eq!(bytes, vec![0, 9]);
+        let (decoded, consumed) = Event::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 2);
+        assert_eq!(decoded, event);
+    }
+}
+
+mod generic_newtypes {
+    use super::*;
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    struct Counter {
+        value: u16,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    #[bebytes(transparent)]
+    struct Wrapper<T>(T);
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    #[bebytes(transparent)]
+    struct List<T>(Vec<T>);
+
+    #[test]
+    fn test_generic_transparent() {
+        let wrapped: Wrapper<Counter> = Counter { value: 0x1234 }.into();
+        assert_eq!(wrapped.to_be_bytes(), vec![0x12, 0x34]);
+        assert_eq!(Wrapper::<Counter>::field_size(), 2);
+    }
+
+    #[test]
+    fn test_generic_transparent_vec() {
+        let list = List::from(vec![Counter { value: 1 }, Counter { value: 2 }]);
+        let bytes = list.to_be_bytes();
+        assert_eq!(bytes, vec![0, 1, 0, 2]);
+
+        let (decoded, _) = List::<Counter>::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(
+            Vec::from(decoded),
+            vec![Counter { value: 1 }, Counter { value: 2 }]
+        );
+    }
+}
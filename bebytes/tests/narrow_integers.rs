@@ -0,0 +1,141 @@
+//! Odd-width integer tests for BeBytes
+//!
+//! This module tests:
+//! - Integers stored in fewer bytes than their type with `#[bebytes(bytes = N)]`
+//! - Sign extension of signed values
+//! - Both byte orders
+//! - Raw pointer encoding of structs with narrow integers
+//! - Range checks when encoding
+
+use bebytes::BeBytes;
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct HandshakeHeader {
+    msg_type: u8,
+    #[bebytes(bytes = 3)]
+    length: u32,
+}
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct Sample {
+    #[bebytes(bytes = 3)]
+    left: i32,
+    #[bebytes(bytes = 3)]
+    right: i32,
+}
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct Identifiers {
+    #[bebytes(bytes = 6)]
+    mac: u64,
+    #[bebytes(bytes = 5)]
+    offset: i64,
+    tail: u16,
+}
+
+#[test]
+fn test_u24_round_trip() {
+    let header = HandshakeHeader {
+        msg_type: 1,
+        length: 0x010203,
+    };
+    assert_eq!(HandshakeHeader::field_size(), 4);
+
+    let bytes = header.to_be_bytes();
+    assert_eq!(bytes, vec![1, 0x01, 0x02, 0x03]);
+    let (decoded, consumed) = HandshakeHeader::try_from_be_bytes(&bytes).unwrap();
+    assert_eq!(consumed, 4);
+    assert_eq!(decoded, header);
+
+    let bytes = header.to_le_bytes();
+    assert_eq!(bytes, vec![1, 0x03, 0x02, 0x01]);
+    let (decoded, _) = HandshakeHeader::try_from_le_bytes(&bytes).unwrap();
+    assert_eq!(decoded, header);
+}
+
+#[test]
+fn test_signed_values_are_sign_extended() {
+    let sample = Sample {
+        left: -2,
+        right: 0x7FFFFF,
+    };
+
+    let bytes = sample.to_be_bytes();
+    assert_eq!(bytes, vec![0xFF, 0xFF, 0xFE, 0x7F, 0xFF, 0xFF]);
+    let (decoded, _) = Sample::try_from_be_bytes(&bytes).unwrap();
+    assert_eq!(decoded, sample);
+
+    let (decoded, _) = Sample::try_from_le_bytes(&[0x00, 0x00, 0x80, 0x01, 0x00, 0x00]).unwrap();
+    assert_eq!(decoded.left, -0x800000);
+    assert_eq!(decoded.right, 1);
+}
+
+#[test]
+fn test_wide_narrow_integers() {
+    let ids = Identifiers {
+        mac: 0x0011_2233_4455,
+        offset: -1,
+        tail: 0xABCD,
+    };
+    assert_eq!(Identifiers::field_size(), 13);
+
+    let bytes = ids.to_be_bytes();
+    assert_eq!(
+        bytes,
+        vec![0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xAB, 0xCD]
+    );
+    let (decoded, consumed) = Identifiers::try_from_be_bytes(&bytes).unwrap();
+    assert_eq!(consumed, 13);
+    assert_eq!(decoded, ids);
+
+    let (decoded, _) = Identifiers::try_from_le_bytes(&ids.to_le_bytes()).unwrap();
+    assert_eq!(decoded, ids);
+}
+
+#[test]
+fn test_raw_pointer_encoding() {
+    assert!(Identifiers::supports_raw_pointer_encoding());
+    assert_eq!(Identifiers::RAW_POINTER_SIZE, 13);
+    assert_eq!(HandshakeHeader::RAW_POINTER_SIZE, 4);
+
+    let ids = Identifiers {
+        mac: 0x0011_2233_4455,
+        offset: -3,
+        tail: 7,
+    };
+    assert_eq!(ids.encode_be_to_raw_stack().to_vec(), ids.to_be_bytes());
+    assert_eq!(ids.encode_le_to_raw_stack().to_vec(), ids.to_le_bytes());
+}
+
+#[test]
+fn test_encode_to_buffer() {
+    let header = HandshakeHeader::new(2, 0xFFFFFF);
+    let mut buf = bebytes::BytesMut::with_capacity(HandshakeHeader::field_size());
+    header.encode_le_to(&mut buf).unwrap();
+    assert_eq!(buf.to_vec(), vec![2, 0xFF, 0xFF, 0xFF]);
+}
+
+#[test]
+fn test_insufficient_data() {
+    let result = HandshakeHeader::try_from_be_bytes(&[1, 0, 0]);
+    assert!(matches!(
+        result,
+        Err(bebytes::BeBytesError::InsufficientData { .. })
+    ));
+}
+
+#[test]
+#[should_panic(expected = "does not fit in 3 bytes")]
+fn test_unsigned_out_of_range_panics() {
+    let _ = HandshakeHeader::new(1, 0x0100_0000);
+}
+
+#[test]
+#[should_panic(expected = "does not fit in 3 bytes")]
+fn test_signed_out_of_range_panics() {
+    let sample = Sample {
+        left: -0x800001,
+        right: 0,
+    };
+    let _ = sample.to_be_bytes();
+}
@@ -176,6 +176,17 @@ fn test_error_formatting_no_std() {
     assert_eq!(formatted, "Invalid discriminant 42 for type TestEnum");
 }
 
+#[test]
+fn test_enum_introspection_no_std() {
+    use alloc::string::ToString;
+
+    assert_eq!(TestEnum::ALL.len(), 3);
+    assert_eq!(TestEnum::Variant3.name(), "Variant3");
+    assert_eq!(TestEnum::Variant1.to_string(), "Variant1");
+    assert_eq!("Variant2".parse::<TestEnum>(), Ok(TestEnum::Variant2));
+    assert!("Variant4".parse::<TestEnum>().is_err());
+}
+
 #[derive(BeBytes, Debug, PartialEq)]
 struct VectorFromField {
     len: u8,
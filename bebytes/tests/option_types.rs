@@ -194,6 +194,7 @@ fn test_option_i128_round_trip() {
 }
 
 #[test]
+#[allow(clippy::approx_constant)]
 fn test_option_f32_round_trip() {
     for val in [0.0f32, 1.0, -1.0, 3.14159, f32::MAX, f32::MIN] {
         let s = OptionF32 { value: Some(val) };
@@ -472,3 +473,316 @@ fn test_option_little_endian_round_trip() {
     let (parsed_none, _) = OptionU32::try_from_le_bytes(&none_bytes).unwrap();
     assert_eq!(parsed_none.value, None);
 }
+
+#[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+struct Header {
+    version: u8,
+    length: u16,
+}
+
+#[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+enum Color {
+    Red = 1,
+    Green = 2,
+}
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct OptionalParts {
+    header: Option<Header>,
+    color: Option<Color>,
+    trailer: u8,
+}
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct OptionalPayload {
+    len: u8,
+    #[FromField(len)]
+    payload: Option<Vec<u8>>,
+    #[With(size(2))]
+    samples: Option<Vec<u16>>,
+    #[With(size(3))]
+    code: Option<String>,
+    name: Option<String>,
+}
+
+#[test]
+fn test_option_struct_and_enum() {
+    let parts = OptionalParts {
+        header: Some(Header {
+            version: 1,
+            length: 0x0203,
+        }),
+        color: Some(Color::Green),
+        trailer: 0xFF,
+    };
+    assert_eq!(OptionalParts::field_size(), 1 + 3 + 1 + 1 + 1);
+
+    let bytes = parts.to_be_bytes();
+    assert_eq!(bytes, vec![0x01, 1, 2, 3, 0x01, 2, 0xFF]);
+    let (parsed, consumed) = OptionalParts::try_from_be_bytes(&bytes).unwrap();
+    assert_eq!(consumed, 7);
+    assert_eq!(parsed, parts);
+
+    let bytes = parts.to_le_bytes();
+    assert_eq!(bytes, vec![0x01, 1, 3, 2, 0x01, 2, 0xFF]);
+    let (parsed, _) = OptionalParts::try_from_le_bytes(&bytes).unwrap();
+    assert_eq!(parsed, parts);
+}
+
+#[test]
+fn test_option_struct_none_is_padded_to_field_size() {
+    let parts = OptionalParts {
+        header: None,
+        color: None,
+        trailer: 7,
+    };
+
+    let bytes = parts.to_be_bytes();
+    assert_eq!(bytes.len(), OptionalParts::field_size());
+    assert_eq!(
+        bytes.len(),
+        1 + Header::field_size() + 1 + Color::field_size() + 1
+    );
+    assert_eq!(bytes, vec![0x00, 0, 0, 0, 0x00, 0, 7]);
+    let (parsed, consumed) = OptionalParts::try_from_be_bytes(&bytes).unwrap();
+    assert_eq!(consumed, 7);
+    assert_eq!(parsed, parts);
+    assert_eq!(parts.to_le_bytes(), bytes);
+
+    let mut buf = bebytes::BytesMut::with_capacity(OptionalParts::field_size());
+    parts.encode_be_to(&mut buf).unwrap();
+    assert_eq!(buf.to_vec(), bytes);
+
+    let invalid_color = [0x00, 0, 0, 0, 0x01, 9, 7];
+    assert!(OptionalParts::try_from_be_bytes(&invalid_color).is_err());
+    assert!(OptionalParts::try_from_be_bytes(&bytes[..4]).is_err());
+}
+
+#[test]
+fn test_option_vectors_and_strings() {
+    let payload = OptionalPayload {
+        len: 2,
+        payload: Some(vec![0xAA, 0xBB]),
+        samples: Some(vec![1, 0x0203]),
+        code: Some("abc".to_string()),
+        name: Some("node".to_string()),
+    };
+
+    let bytes = payload.to_be_bytes();
+    assert_eq!(
+        bytes,
+        vec![
+            2, 0x01, 0xAA, 0xBB, 0x01, 0, 1, 2, 3, 0x01, b'a', b'b', b'c', 0x01, b'n', b'o', b'd',
+            b'e'
+        ]
+    );
+    let (parsed, consumed) = OptionalPayload::try_from_be_bytes(&bytes).unwrap();
+    assert_eq!(consumed, bytes.len());
+    assert_eq!(parsed, payload);
+
+    let (parsed, _) = OptionalPayload::try_from_le_bytes(&payload.to_le_bytes()).unwrap();
+    assert_eq!(parsed, payload);
+
+    let mut buf = bebytes::BytesMut::with_capacity(bytes.len());
+    payload.encode_be_to(&mut buf).unwrap();
+    assert_eq!(buf.to_vec(), bytes);
+}
+
+#[test]
+fn test_option_vectors_and_strings_none() {
+    let payload = OptionalPayload {
+        len: 0,
+        payload: None,
+        samples: None,
+        code: None,
+        name: None,
+    };
+
+    let bytes = payload.to_be_bytes();
+    assert_eq!(bytes, vec![0, 0x00, 0x00, 0x00, 0x00]);
+    let (parsed, consumed) = OptionalPayload::try_from_be_bytes(&bytes).unwrap();
+    assert_eq!(consumed, 5);
+    assert_eq!(parsed, payload);
+}
+
+#[test]
+fn test_option_missing_tag() {
+    let result = OptionalParts::try_from_be_bytes(&[0x00]);
+    assert!(matches!(
+        result,
+        Err(bebytes::BeBytesError::InsufficientData { .. })
+    ));
+}
+
+#[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+struct PresenceFlags {
+    #[bits(1)]
+    has_timestamp: u8,
+    #[bits(7)]
+    reserved: u8,
+}
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct PresenceFrame {
+    flags: PresenceFlags,
+    has_payload: bool,
+    len: u8,
+    #[bebytes(present_if = flags.has_timestamp)]
+    timestamp: Option<u32>,
+    #[FromField(len)]
+    #[bebytes(present_if = has_payload)]
+    payload: Option<Vec<u8>>,
+}
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct SentinelValues {
+    #[bebytes(none_value = 0xFFFF)]
+    port: Option<u16>,
+    #[bebytes(none_value = -1)]
+    offset: Option<i32>,
+}
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct WideTags {
+    #[bebytes(tag_type = u16)]
+    value: Option<u8>,
+    #[bebytes(tag_type = u32)]
+    header: Option<Header>,
+}
+
+#[test]
+fn test_option_present_if() {
+    let frame = PresenceFrame {
+        flags: PresenceFlags {
+            has_timestamp: 1,
+            reserved: 0,
+        },
+        has_payload: true,
+        len: 2,
+        timestamp: Some(0x01020304),
+        payload: Some(vec![0xAA, 0xBB]),
+    };
+
+    let bytes = frame.to_be_bytes();
+    assert_eq!(bytes, vec![0x80, 1, 2, 1, 2, 3, 4, 0xAA, 0xBB]);
+    let (parsed, consumed) = PresenceFrame::try_from_be_bytes(&bytes).unwrap();
+    assert_eq!(consumed, 9);
+    assert_eq!(parsed, frame);
+
+    let mut buf = bebytes::BytesMut::with_capacity(bytes.len());
+    frame.encode_be_to(&mut buf).unwrap();
+    assert_eq!(buf.to_vec(), bytes);
+}
+
+#[test]
+fn test_option_present_if_absent_takes_no_space() {
+    let frame = PresenceFrame {
+        flags: PresenceFlags {
+            has_timestamp: 0,
+            reserved: 0,
+        },
+        has_payload: false,
+        len: 0,
+        timestamp: None,
+        payload: None,
+    };
+
+    let bytes = frame.to_le_bytes();
+    assert_eq!(bytes, vec![0x00, 0, 0]);
+    let (parsed, consumed) = PresenceFrame::try_from_le_bytes(&bytes).unwrap();
+    assert_eq!(consumed, 3);
+    assert_eq!(parsed, frame);
+}
+
+#[test]
+#[should_panic(expected = "Presence field has_payload is false but payload is Some")]
+fn test_option_present_if_mismatch_panics() {
+    let frame = PresenceFrame {
+        flags: PresenceFlags {
+            has_timestamp: 0,
+            reserved: 0,
+        },
+        has_payload: false,
+        len: 1,
+        timestamp: None,
+        payload: Some(vec![1]),
+    };
+    let _ = frame.to_be_bytes();
+}
+
+#[test]
+fn test_option_none_value() {
+    let values = SentinelValues {
+        port: Some(80),
+        offset: Some(-2),
+    };
+    assert_eq!(SentinelValues::field_size(), 6);
+
+    let bytes = values.to_be_bytes();
+    assert_eq!(bytes, vec![0x00, 0x50, 0xFF, 0xFF, 0xFF, 0xFE]);
+    let (parsed, _) = SentinelValues::try_from_be_bytes(&bytes).unwrap();
+    assert_eq!(parsed, values);
+
+    let absent = SentinelValues {
+        port: None,
+        offset: None,
+    };
+    let bytes = absent.to_le_bytes();
+    assert_eq!(bytes, vec![0xFF; 6]);
+    let (parsed, _) = SentinelValues::try_from_le_bytes(&bytes).unwrap();
+    assert_eq!(parsed, absent);
+}
+
+#[test]
+#[should_panic(expected = "port holds its none_value 65535")]
+fn test_option_none_value_collision_panics() {
+    let values = SentinelValues {
+        port: Some(0xFFFF),
+        offset: None,
+    };
+    let _ = values.to_be_bytes();
+}
+
+#[test]
+fn test_option_tag_type() {
+    let tags = WideTags {
+        value: Some(5),
+        header: Some(Header {
+            version: 1,
+            length: 2,
+        }),
+    };
+    assert_eq!(WideTags::field_size(), 3 + 4 + 3);
+
+    let bytes = tags.to_be_bytes();
+    assert_eq!(bytes, vec![0, 1, 5, 0, 0, 0, 1, 1, 0, 2]);
+    let (parsed, _) = WideTags::try_from_be_bytes(&bytes).unwrap();
+    assert_eq!(parsed, tags);
+
+    let bytes = tags.to_le_bytes();
+    assert_eq!(bytes, vec![1, 0, 5, 1, 0, 0, 0, 1, 2, 0]);
+    let (parsed, _) = WideTags::try_from_le_bytes(&bytes).unwrap();
+    assert_eq!(parsed, tags);
+}
+
+#[test]
+fn test_option_tag_type_none() {
+    let tags = WideTags {
+        value: None,
+        header: None,
+    };
+
+    // Both values are padded, so the size doesn't depend on presence
+    let bytes = tags.to_be_bytes();
+    assert_eq!(bytes, vec![0; 10]);
+    assert_eq!(bytes.len(), WideTags::field_size());
+    let (parsed, consumed) = WideTags::try_from_be_bytes(&bytes).unwrap();
+    assert_eq!(consumed, 10);
+    assert_eq!(parsed, tags);
+
+    let result = WideTags::try_from_be_bytes(&[0, 2, 0, 0, 0, 0, 0]);
+    assert!(matches!(
+        result,
+        Err(bebytes::BeBytesError::InvalidDiscriminantLarge { value: 2, .. })
+    ));
+}
@@ -0,0 +1,446 @@
+//! Data-carrying enum tests for BeBytes
+//!
+//! This module tests:
+//! - Tuple, struct and unit variants selected by a leading tag
+//! - Tags from `#[bebytes(tag = N)]`, explicit discriminants and auto-increment
+//! - Tag width and byte order configuration
+//! - Struct features inside variant bodies (bit fields, `FromField`, markers)
+//! - Data-carrying enums nested in structs
+//! - `#[bebytes(tag_from = ...)]` fields selected by an earlier field
+//! - Error reporting for unknown tags and truncated input
+
+use bebytes::{BeBytes, BeBytesError, TaggedBody};
+
+mod basic_variants {
+    use super::*;
+
+    #[derive(BeBytes, Debug, PartialEq, Clone)]
+    struct Payload {
+        kind: u8,
+        value: u16,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    enum Msg {
+        #[bebytes(tag = 1)]
+        Ping { seq: u32 },
+        #[bebytes(tag = 2)]
+        Data(Payload),
+        #[bebytes(tag = 3)]
+        Close,
+    }
+
+    #[test]
+    fn test_struct_variant_round_trip() {
+        let msg = Msg::Ping { seq: 0x01020304 };
+
+        let be_bytes = msg.to_be_bytes();
+        assert_eq!(be_bytes, vec![1, 1, 2, 3, 4]);
+        let (decoded, consumed) = Msg::try_from_be_bytes(&be_bytes).unwrap();
+        assert_eq!(consumed, 5);
+        assert_eq!(decoded, msg);
+
+        let le_bytes = msg.to_le_bytes();
+        assert_eq!(le_bytes, vec![1, 4, 3, 2, 1]);
+        let (decoded, _) = Msg::try_from_le_bytes(&le_bytes).unwrap();
+        assert_eq!(decoded, msg);
+    }
+
+    #[test]
+    fn test_tuple_variant_round_trip() {
+        let msg = Msg::Data(Payload {
+            kind: 9,
+            value: 0xABCD,
+        });
+
+        let bytes = msg.to_be_bytes();
+        assert_eq!(bytes, vec![2, 9, 0xAB, 0xCD]);
+        let (decoded, consumed) = Msg::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 4);
+        assert_eq!(decoded, msg);
+    }
+
+    #[test]
+    fn test_unit_variant_encodes_only_tag() {
+        assert_eq!(Msg::Close.to_be_bytes(), vec![3]);
+        let (decoded, consumed) = Msg::try_from_be_bytes(&[3, 0xFF]).unwrap();
+        assert_eq!(consumed, 1);
+        assert_eq!(decoded, Msg::Close);
+    }
+
+    #[test]
+    fn test_field_size_is_largest_variant() {
+        // One tag byte plus the four bytes of `Ping`
+        assert_eq!(Msg::field_size(), 5);
+    }
+
+    #[test]
+    fn test_unknown_tag() {
+        let result = Msg::try_from_be_bytes(&[7, 0, 0, 0, 0]);
+        assert_eq!(
+            result,
+            Err(BeBytesError::InvalidDiscriminant {
+                value: 7,
+                type_name: "Msg",
+            })
+        );
+    }
+
+    #[test]
+    fn test_empty_and_truncated_input() {
+        assert_eq!(Msg::try_from_be_bytes(&[]), Err(BeBytesError::EmptyBuffer));
+        assert!(Msg::try_from_be_bytes(&[1, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn test_encode_to_buffer() {
+        let msg = Msg::Ping { seq: 5 };
+        let mut buf = bebytes::BytesMut::with_capacity(16);
+        msg.encode_be_to(&mut buf).unwrap();
+        assert_eq!(buf.to_vec(), msg.to_be_bytes());
+    }
+}
+
+mod tag_assignment {
+    use super::*;
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    enum Implicit {
+        First(u8),
+        Second(u8),
+        Third { a: u8, b: u8 },
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    #[repr(u8)]
+    enum Discriminants {
+        Low(u8) = 0x10,
+        Next(u8),
+        High { value: u16 } = 0x80,
+    }
+
+    #[test]
+    fn test_tags_follow_declaration_order() {
+        assert_eq!(Implicit::First(7).to_be_bytes(), vec![0, 7]);
+        assert_eq!(Implicit::Second(7).to_be_bytes(), vec![1, 7]);
+        assert_eq!(Implicit::Third { a: 1, b: 2 }.to_be_bytes(), vec![2, 1, 2]);
+    }
+
+    #[test]
+    fn test_explicit_discriminants_are_tags() {
+        assert_eq!(Discriminants::Low(1).to_be_bytes(), vec![0x10, 1]);
+        assert_eq!(Discriminants::Next(1).to_be_bytes(), vec![0x11, 1]);
+
+        let high = Discriminants::High { value: 0x0102 };
+        let bytes = high.to_be_bytes();
+        assert_eq!(bytes, vec![0x80, 1, 2]);
+        let (decoded, _) = Discriminants::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(decoded, high);
+    }
+}
+
+mod tag_configuration {
+    use super::*;
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    #[bebytes(tag_type = u16)]
+    enum Wide {
+        #[bebytes(tag = 0x0102)]
+        Value(u16),
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    #[bebytes(tag_type = u16, tag_endian = "big")]
+    enum FixedOrder {
+        #[bebytes(tag = 0x0102)]
+        Value(u16),
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    enum LargeTags {
+        #[bebytes(tag = 0x1000)]
+        Value(u8),
+    }
+
+    #[test]
+    fn test_tag_width_follows_method_endianness() {
+        let value = Wide::Value(0x0A0B);
+        assert_eq!(value.to_be_bytes(), vec![0x01, 0x02, 0x0A, 0x0B]);
+        assert_eq!(value.to_le_bytes(), vec![0x02, 0x01, 0x0B, 0x0A]);
+
+        let (decoded, consumed) = Wide::try_from_le_bytes(&value.to_le_bytes()).unwrap();
+        assert_eq!(consumed, 4);
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn test_fixed_tag_endianness() {
+        let value = FixedOrder::Value(0x0A0B);
+        assert_eq!(value.to_le_bytes(), vec![0x01, 0x02, 0x0B, 0x0A]);
+
+        let (decoded, _) = FixedOrder::try_from_le_bytes(&value.to_le_bytes()).unwrap();
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn test_tag_width_detected_from_largest_tag() {
+        assert_eq!(LargeTags::Value(1).to_be_bytes(), vec![0x10, 0x00, 1]);
+    }
+
+    #[test]
+    fn test_unknown_wide_tag() {
+        let result = Wide::try_from_be_bytes(&[0xFF, 0xFF, 0, 0]);
+        assert_eq!(
+            result,
+            Err(BeBytesError::InvalidDiscriminantLarge {
+                value: 0xFFFF,
+                type_name: "Wide",
+            })
+        );
+    }
+}
+
+mod variant_bodies {
+    use super::*;
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    enum Record {
+        #[bebytes(tag = 1)]
+        Flags {
+            #[bits(1)]
+            urgent: u8,
+            #[bits(3)]
+            priority: u8,
+            #[bits(4)]
+            channel: u8,
+            length: u16,
+        },
+        #[bebytes(tag = 2)]
+        Counted {
+            count: u8,
+            #[FromField(count)]
+            items: Vec<u8>,
+            trailer: u8,
+        },
+        #[bebytes(tag = 3)]
+        Text(#[UntilMarker(0)] Vec<u8>, u8),
+        #[bebytes(tag = 4)]
+        Rest(u8, Vec<u8>),
+    }
+
+    #[test]
+    fn test_bit_fields_in_variant() {
+        let record = Record::Flags {
+            urgent: 1,
+            priority: 5,
+            channel: 0xA,
+            length: 0x1234,
+        };
+
+        let be_bytes = record.to_be_bytes();
+        assert_eq!(be_bytes, vec![1, 0b1101_1010, 0x12, 0x34]);
+        let (decoded, _) = Record::try_from_be_bytes(&be_bytes).unwrap();
+        assert_eq!(decoded, record);
+
+        let (decoded, _) = Record::try_from_le_bytes(&record.to_le_bytes()).unwrap();
+        assert_eq!(decoded, record);
+    }
+
+    #[test]
+    fn test_from_field_in_variant() {
+        let record = Record::Counted {
+            count: 3,
+            items: vec![7, 8, 9],
+            trailer: 0xEE,
+        };
+
+        let bytes = record.to_be_bytes();
+        assert_eq!(bytes, vec![2, 3, 7, 8, 9, 0xEE]);
+        let (decoded, consumed) = Record::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 6);
+        assert_eq!(decoded, record);
+    }
+
+    #[test]
+    fn test_marker_in_variant() {
+        let record = Record::Text(b"hi".to_vec(), 5);
+
+        let bytes = record.to_be_bytes();
+        assert_eq!(bytes, vec![3, b'h', b'i', 0, 5]);
+        let (decoded, _) = Record::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(decoded, record);
+    }
+
+    #[test]
+    fn test_trailing_vector_consumes_rest() {
+        let record = Record::Rest(1, vec![2, 3, 4]);
+
+        let bytes = record.to_be_bytes();
+        assert_eq!(bytes, vec![4, 1, 2, 3, 4]);
+        let (decoded, consumed) = Record::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 5);
+        assert_eq!(decoded, record);
+    }
+}
+
+mod nested {
+    use super::*;
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    enum Command {
+        #[bebytes(tag = 0x10)]
+        Move { x: i16, y: i16 },
+        #[bebytes(tag = 0x20)]
+        Stop,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Frame {
+        id: u8,
+        command: Command,
+        checksum: u8,
+    }
+
+    #[test]
+    fn test_enum_as_struct_field() {
+        let frame = Frame {
+            id: 1,
+            command: Command::Move { x: -1, y: 2 },
+            checksum: 0x55,
+        };
+
+        let bytes = frame.to_be_bytes();
+        assert_eq!(bytes, vec![1, 0x10, 0xFF, 0xFF, 0x00, 0x02, 0x55]);
+        let (decoded, consumed) = Frame::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 7);
+        assert_eq!(decoded, frame);
+    }
+
+    #[test]
+    fn test_shorter_variant_as_struct_field() {
+        let frame = Frame {
+            id: 2,
+            command: Command::Stop,
+            checksum: 0x66,
+        };
+
+        let bytes = frame.to_be_bytes();
+        assert_eq!(bytes, vec![2, 0x20, 0x66]);
+        let (decoded, consumed) = Frame::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 3);
+        assert_eq!(decoded, frame);
+    }
+}
+
+mod tag_from_field {
+    use super::*;
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    struct Header {
+        #[bits(4)]
+        version: u8,
+        #[bits(4)]
+        msg_type: u8,
+        length: u16,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    enum Body {
+        #[bebytes(tag = 1)]
+        Ping { seq: u32 },
+        #[bebytes(tag = 2)]
+        Ack(u16),
+        #[bebytes(tag = 3)]
+        Reset,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Packet {
+        header: Header,
+        flags: u8,
+        #[bebytes(tag_from = header.msg_type)]
+        body: Body,
+        crc: u8,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Compact(u8, #[bebytes(tag_from = 0)] Body);
+
+    fn packet(msg_type: u8, body: Body) -> Packet {
+        Packet {
+            header: Header {
+                version: 1,
+                msg_type,
+                length: 0x0102,
+            },
+            flags: 0xF0,
+            body,
+            crc: 0x55,
+        }
+    }
+
+    #[test]
+    fn test_body_has_no_leading_tag() {
+        let packet = packet(1, Body::Ping { seq: 0x0A0B0C0D });
+
+        let bytes = packet.to_be_bytes();
+        assert_eq!(
+            bytes,
+            vec![0x11, 0x01, 0x02, 0xF0, 0x0A, 0x0B, 0x0C, 0x0D, 0x55]
+        );
+        let (decoded, consumed) = Packet::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 9);
+        assert_eq!(decoded, packet);
+    }
+
+    #[test]
+    fn test_variant_selected_by_header() {
+        for packet in [packet(2, Body::Ack(0x1234)), packet(3, Body::Reset)] {
+            let (decoded, _) = Packet::try_from_be_bytes(&packet.to_be_bytes()).unwrap();
+            assert_eq!(decoded, packet);
+
+            let (decoded, _) = Packet::try_from_le_bytes(&packet.to_le_bytes()).unwrap();
+            assert_eq!(decoded, packet);
+        }
+    }
+
+    #[test]
+    fn test_positional_tag_field() {
+        let compact = Compact(2, Body::Ack(7));
+
+        let bytes = compact.to_be_bytes();
+        assert_eq!(bytes, vec![2, 0, 7]);
+        let (decoded, _) = Compact::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(decoded, compact);
+    }
+
+    #[test]
+    fn test_unknown_tag_in_header() {
+        let bytes = [0x19, 0x00, 0x00, 0xF0, 0x55];
+        assert_eq!(
+            Packet::try_from_be_bytes(&bytes),
+            Err(BeBytesError::InvalidDiscriminant {
+                value: 9,
+                type_name: "Body",
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Tag field")]
+    fn test_mismatched_tag_panics_on_encode() {
+        let _ = packet(1, Body::Reset).to_be_bytes();
+    }
+
+    #[test]
+    fn test_tagged_body_api() {
+        let body = Body::Ack(0x0102);
+        assert_eq!(body.tag(), 2);
+        assert_eq!(body.to_be_body(), vec![1, 2]);
+        assert_eq!(body.to_le_body(), vec![2, 1]);
+
+        let (decoded, consumed) = Body::try_from_be_body(2, &[1, 2]).unwrap();
+        assert_eq!(consumed, 2);
+        assert_eq!(decoded, body);
+    }
+}
@@ -0,0 +1,187 @@
+//! Tuple struct and newtype tests for BeBytes
+//!
+//! This module tests:
+//! - Positional fields with primitives, arrays and nested structs
+//! - Bit fields on positional fields
+//! - `#[FromField]` referencing a positional field by index
+//! - Marker attributes on positional fields
+//! - `#[bebytes(transparent)]` newtypes
+
+use bebytes::BeBytes;
+
+mod positional_fields {
+    use super::*;
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    struct SessionId(u32);
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    struct Rgb(u8, u8, u8);
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Frame(SessionId, Rgb, [u8; 2]);
+
+    #[test]
+    fn test_newtype_round_trip() {
+        let id = SessionId(0xDEADBEEF);
+
+        let be_bytes = id.to_be_bytes();
+        assert_eq!(be_bytes, vec![0xDE, 0xAD, 0xBE, 0xEF]);
+        let (decoded, consumed) = SessionId::try_from_be_bytes(&be_bytes).unwrap();
+        assert_eq!(consumed, 4);
+        assert_eq!(decoded, id);
+
+        let le_bytes = id.to_le_bytes();
+        assert_eq!(le_bytes, vec![0xEF, 0xBE, 0xAD, 0xDE]);
+        let (decoded, _) = SessionId::try_from_le_bytes(&le_bytes).unwrap();
+        assert_eq!(decoded, id);
+    }
+
+    #[test]
+    fn test_multiple_positional_fields() {
+        let color = Rgb(0x11, 0x22, 0x33);
+        assert_eq!(Rgb::field_size(), 3);
+        assert_eq!(color.to_be_bytes(), vec![0x11, 0x22, 0x33]);
+        assert_eq!(Rgb::new(0x11, 0x22, 0x33), color);
+    }
+
+    #[test]
+    fn test_nested_tuple_structs() {
+        let frame = Frame(SessionId(7), Rgb(1, 2, 3), [0xAA, 0xBB]);
+
+        let bytes = frame.to_be_bytes();
+        assert_eq!(bytes, vec![0, 0, 0, 7, 1, 2, 3, 0xAA, 0xBB]);
+
+        let (decoded, consumed) = Frame::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 9);
+        assert_eq!(decoded, frame);
+    }
+
+    #[test]
+    fn test_raw_pointer_encoding() {
+        assert!(Rgb::supports_raw_pointer_encoding());
+        assert_eq!(Rgb::RAW_POINTER_SIZE, 3);
+        let color = Rgb(9, 8, 7);
+        assert_eq!(color.encode_be_to_raw_stack(), [9, 8, 7]);
+    }
+}
+
+mod positional_attributes {
+    use super::*;
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Flags(#[bits(1)] u8, #[bits(3)] u8, #[bits(4)] u8, u16);
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Counted(u8, #[FromField(0)] Vec<u8>, u8);
+
+    #[derive(BeBytes, Debug, PartialEq, Clone)]
+    struct Header(u16, u8);
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Delimited(u8, #[UntilMarker(0xFF)] Vec<u8>, u8);
+
+    #[test]
+    fn test_bit_fields() {
+        let flags = Flags(1, 5, 0xA, 0x1234);
+        let bytes = flags.to_be_bytes();
+        assert_eq!(bytes, vec![0b1101_1010, 0x12, 0x34]);
+
+        let (decoded, _) = Flags::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(decoded, flags);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn test_bit_field_limit_check() {
+        let _ = Flags::new(2, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_from_field_by_index() {
+        let counted = Counted(3, vec![1, 2, 3], 0xEE);
+        let bytes = counted.to_be_bytes();
+        assert_eq!(bytes, vec![3, 1, 2, 3, 0xEE]);
+
+        let (decoded, consumed) = Counted::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 5);
+        assert_eq!(decoded, counted);
+    }
+
+    #[test]
+    fn test_marker_on_positional_field() {
+        let delimited = Delimited(1, vec![0x10, 0x20], 2);
+        let bytes = delimited.to_be_bytes();
+        assert_eq!(bytes, vec![1, 0x10, 0x20, 0xFF, 2]);
+
+        let (decoded, _) = Delimited::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(decoded, delimited);
+    }
+
+    #[test]
+    fn test_tuple_struct_as_named_field() {
+        #[derive(BeBytes, Debug, PartialEq)]
+        struct Message {
+            header: Header,
+            trailer: u8,
+        }
+
+        let message = Message {
+            header: Header(0x0102, 3),
+            trailer: 4,
+        };
+        let bytes = message.to_be_bytes();
+        assert_eq!(bytes, vec![1, 2, 3, 4]);
+
+        let (decoded, _) = Message::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(decoded, message);
+    }
+}
+
+mod transparent_newtypes {
+    use super::*;
+
+    #[derive(BeBytes, Debug, PartialEq, Clone, Copy)]
+    #[bebytes(transparent)]
+    struct Port(u16);
+
+    #[derive(BeBytes, Debug, PartialEq, Clone)]
+    #[bebytes(transparent)]
+    struct Payload(Vec<u8>);
+
+    #[derive(BeBytes, Debug, PartialEq, Clone)]
+    #[bebytes(transparent)]
+    struct Named {
+        inner: u32,
+    }
+
+    #[test]
+    fn test_transparent_encodes_like_inner() {
+        let port = Port(8080);
+        assert_eq!(port.to_be_bytes(), 8080u16.to_be_bytes().to_vec());
+        assert_eq!(port.to_le_bytes(), 8080u16.to_le_bytes().to_vec());
+        assert_eq!(Port::field_size(), 2);
+    }
+
+    #[test]
+    fn test_transparent_conversions() {
+        let port: Port = 53u16.into();
+        assert_eq!(port, Port(53));
+        let raw: u16 = port.into();
+        assert_eq!(raw, 53);
+
+        let named = Named::from(0xAABBCCDD);
+        assert_eq!(u32::from(named), 0xAABBCCDD);
+    }
+
+    #[test]
+    fn test_transparent_vec_consumes_remaining() {
+        let payload = Payload(vec![1, 2, 3, 4]);
+        let bytes = payload.to_be_bytes();
+        assert_eq!(bytes, vec![1, 2, 3, 4]);
+
+        let (decoded, consumed) = Payload::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 4);
+        assert_eq!(decoded, payload);
+    }
+}
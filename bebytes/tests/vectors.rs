@@ -5,8 +5,9 @@
 //! - Dynamic size vectors with #[FromField(field_name)]
 //! - Vectors as the last field
 //! - Nested field access for vector sizes
+//! - Vectors of multi-byte primitives with per-element byte order
 
-use bebytes::BeBytes;
+use bebytes::{BeBytes, BeBytesError};
 
 mod fixed_size_vectors {
     use super::*;
@@ -301,3 +302,175 @@ mod custom_type_vectors {
         assert_eq!(decoded.items[1].id, 20);
     }
 }
+
+mod primitive_element_vectors {
+    use super::*;
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Samples {
+        #[With(size(3))]
+        values: Vec<u16>,
+        end: u8,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq, Clone)]
+    struct AddressList {
+        count: u8,
+        #[FromField(count)]
+        addresses: Vec<u32>,
+        flags: u8,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Telemetry {
+        id: u8,
+        readings: Vec<f32>,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Payload {
+        length: u16,
+        #[FromField(length)]
+        #[bebytes(byte_length)]
+        offsets: Vec<i16>,
+    }
+
+    #[derive(BeBytes, Debug, PartialEq)]
+    struct Mixed {
+        #[With(size(2))]
+        signed: Vec<i8>,
+        #[With(size(2))]
+        wide: Vec<u128>,
+        #[With(size(2))]
+        switches: Vec<bool>,
+        #[With(size(1))]
+        letters: Vec<char>,
+        #[With(size(1))]
+        precise: Vec<f64>,
+    }
+
+    #[test]
+    fn test_fixed_count_is_in_elements() {
+        let samples = Samples {
+            values: vec![0x0102, 0x0304, 0x0506],
+            end: 0xFF,
+        };
+        assert_eq!(Samples::field_size(), 7);
+
+        let bytes = samples.to_be_bytes();
+        assert_eq!(bytes, vec![1, 2, 3, 4, 5, 6, 0xFF]);
+        let (decoded, consumed) = Samples::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 7);
+        assert_eq!(decoded, samples);
+
+        let bytes = samples.to_le_bytes();
+        assert_eq!(bytes, vec![2, 1, 4, 3, 6, 5, 0xFF]);
+        let (decoded, _) = Samples::try_from_le_bytes(&bytes).unwrap();
+        assert_eq!(decoded, samples);
+    }
+
+    #[test]
+    fn test_from_field_counts_elements() {
+        let list = AddressList {
+            count: 2,
+            addresses: vec![0xC0A80001, 0x0A000001],
+            flags: 1,
+        };
+
+        let bytes = list.to_be_bytes();
+        assert_eq!(bytes, vec![2, 0xC0, 0xA8, 0, 1, 0x0A, 0, 0, 1, 1]);
+        let (decoded, consumed) = AddressList::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(consumed, 10);
+        assert_eq!(decoded, list);
+
+        let mut buf = bebytes::BytesMut::with_capacity(16);
+        list.encode_le_to(&mut buf).unwrap();
+        let encoded = buf.to_vec();
+        assert_eq!(encoded, list.to_le_bytes());
+        assert_eq!(encoded[1..5], [1, 0, 0xA8, 0xC0]);
+    }
+
+    #[test]
+    fn test_unbounded_last_field() {
+        let telemetry = Telemetry {
+            id: 9,
+            readings: vec![1.5, -2.25],
+        };
+
+        let bytes = telemetry.to_be_bytes();
+        assert_eq!(bytes.len(), 1 + 8);
+        assert_eq!(bytes[1..5], 1.5f32.to_be_bytes());
+        let (decoded, _) = Telemetry::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(decoded, telemetry);
+
+        // A trailing partial element is an error rather than silently dropped
+        let mut truncated = bytes.clone();
+        truncated.pop();
+        assert!(matches!(
+            Telemetry::try_from_be_bytes(&truncated),
+            Err(BeBytesError::InsufficientData { .. })
+        ));
+    }
+
+    #[test]
+    fn test_byte_length() {
+        let payload = Payload {
+            length: 4,
+            offsets: vec![-1, 2],
+        };
+
+        let bytes = payload.to_be_bytes();
+        assert_eq!(bytes, vec![0, 4, 0xFF, 0xFF, 0, 2]);
+        let (decoded, _) = Payload::try_from_be_bytes(&bytes).unwrap();
+        assert_eq!(decoded, payload);
+
+        let (decoded, consumed) = Payload::try_from_be_bytes(&[0, 0]).unwrap();
+        assert_eq!(consumed, 2);
+        assert!(decoded.offsets.is_empty());
+    }
+
+    #[test]
+    fn test_byte_length_splitting_an_element() {
+        // Rejected even though the buffer holds the bytes, naming the field and length
+        let result = Payload::try_from_be_bytes(&[0, 3, 0xFF, 0xFF, 0, 0]);
+        assert_eq!(
+            result,
+            Err(BeBytesError::InvalidByteLength {
+                length: 3,
+                element_size: 2,
+                field: "offsets",
+            })
+        );
+        let result = Payload::try_from_le_bytes(&[5, 0, 0xFF, 0xFF, 0, 0, 0]);
+        assert!(matches!(
+            result,
+            Err(BeBytesError::InvalidByteLength { length: 5, .. })
+        ));
+    }
+
+    #[test]
+    fn test_every_primitive_element_type() {
+        let mixed = Mixed {
+            signed: vec![-1, 1],
+            wide: vec![1, u128::MAX],
+            switches: vec![true, false],
+            letters: vec!['é'],
+            precise: vec![0.1],
+        };
+        assert_eq!(Mixed::field_size(), 2 + 32 + 2 + 4 + 8);
+
+        for bytes in [mixed.to_be_bytes(), mixed.to_le_bytes()] {
+            assert_eq!(bytes.len(), Mixed::field_size());
+        }
+        let (decoded, _) = Mixed::try_from_be_bytes(&mixed.to_be_bytes()).unwrap();
+        assert_eq!(decoded, mixed);
+        let (decoded, _) = Mixed::try_from_le_bytes(&mixed.to_le_bytes()).unwrap();
+        assert_eq!(decoded, mixed);
+    }
+
+    #[test]
+    fn test_insufficient_data() {
+        let result = AddressList::try_from_be_bytes(&[2, 0, 0, 0, 1, 0, 0]);
+        assert!(matches!(result, Err(BeBytesError::InsufficientData { .. })));
+    }
+}
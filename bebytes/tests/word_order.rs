@@ -0,0 +1,138 @@
+//! Word order tests for BeBytes
+//!
+//! This module tests:
+//! - The `ABCD`, `CDAB`, `BADC` and `DCBA` orders of 32 and 64-bit integers and floats
+//! - Vectors of word-ordered values
+//! - Word-ordered fields alongside fields that follow the method's byte order
+//! - Raw pointer encoding of structs with word-ordered fields
+
+use bebytes::BeBytes;
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct Registers {
+    #[bebytes(word_order = "ABCD")]
+    abcd: u32,
+    #[bebytes(word_order = "CDAB")]
+    cdab: u32,
+    #[bebytes(word_order = "BADC")]
+    badc: u32,
+    #[bebytes(word_order = "DCBA")]
+    dcba: u32,
+}
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct Meter {
+    unit_id: u16,
+    #[bebytes(word_order = "CDAB")]
+    energy: u64,
+    #[bebytes(word_order = "CDAB")]
+    voltage: f32,
+    #[bebytes(word_order = "BADC")]
+    offset: i32,
+    #[bebytes(word_order = "BADC")]
+    total: f64,
+}
+
+#[derive(BeBytes, Debug, PartialEq)]
+struct ReadResponse {
+    count: u8,
+    #[bebytes(word_order = "CDAB")]
+    #[FromField(count)]
+    readings: Vec<f32>,
+}
+
+#[test]
+fn test_word_orders() {
+    let value = Registers {
+        abcd: 0x1122_3344,
+        cdab: 0x1122_3344,
+        badc: 0x1122_3344,
+        dcba: 0x1122_3344,
+    };
+
+    let expected = vec![
+        0x11, 0x22, 0x33, 0x44, // ABCD
+        0x33, 0x44, 0x11, 0x22, // CDAB
+        0x22, 0x11, 0x44, 0x33, // BADC
+        0x44, 0x33, 0x22, 0x11, // DCBA
+    ];
+    assert_eq!(value.to_be_bytes(), expected);
+    assert_eq!(value.to_le_bytes(), expected);
+
+    let (decoded, consumed) = Registers::try_from_be_bytes(&expected).unwrap();
+    assert_eq!(consumed, 16);
+    assert_eq!(decoded, value);
+    let (decoded, _) = Registers::try_from_le_bytes(&expected).unwrap();
+    assert_eq!(decoded, value);
+}
+
+#[test]
+fn test_wide_values_and_floats() {
+    let meter = Meter {
+        unit_id: 0x0102,
+        energy: 0x1122_3344_5566_7788,
+        voltage: 1.0,
+        offset: -2,
+        total: 2.5,
+    };
+
+    let bytes = meter.to_be_bytes();
+    assert_eq!(&bytes[..2], &[0x01, 0x02]);
+    assert_eq!(
+        &bytes[2..10],
+        &[0x77, 0x88, 0x55, 0x66, 0x33, 0x44, 0x11, 0x22]
+    );
+    // 1.0f32 is 0x3F80_0000
+    assert_eq!(&bytes[10..14], &[0x00, 0x00, 0x3F, 0x80]);
+    assert_eq!(&bytes[14..18], &[0xFF, 0xFF, 0xFE, 0xFF]);
+    // 2.5f64 is 0x4004_0000_0000_0000
+    assert_eq!(&bytes[18..], &[0x04, 0x40, 0, 0, 0, 0, 0, 0]);
+
+    let (decoded, consumed) = Meter::try_from_be_bytes(&bytes).unwrap();
+    assert_eq!(consumed, 26);
+    assert_eq!(decoded, meter);
+
+    // Only the field without a word order follows the method
+    let le_bytes = meter.to_le_bytes();
+    assert_eq!(&le_bytes[..2], &[0x02, 0x01]);
+    assert_eq!(&le_bytes[2..], &bytes[2..]);
+    let (decoded, _) = Meter::try_from_le_bytes(&le_bytes).unwrap();
+    assert_eq!(decoded, meter);
+}
+
+#[test]
+fn test_vector_of_word_ordered_values() {
+    let response = ReadResponse {
+        count: 2,
+        readings: vec![1.0, -2.0],
+    };
+
+    // -2.0f32 is 0xC000_0000
+    let bytes = response.to_be_bytes();
+    assert_eq!(
+        bytes,
+        vec![2, 0x00, 0x00, 0x3F, 0x80, 0x00, 0x00, 0xC0, 0x00]
+    );
+
+    let (decoded, consumed) = ReadResponse::try_from_be_bytes(&bytes).unwrap();
+    assert_eq!(consumed, 9);
+    assert_eq!(decoded, response);
+
+    let mut buf = bebytes::BytesMut::with_capacity(ReadResponse::field_size());
+    response.encode_le_to(&mut buf).unwrap();
+    assert_eq!(buf.to_vec(), bytes);
+}
+
+#[test]
+fn test_raw_pointer_encoding() {
+    let value = Registers {
+        abcd: 0x0A0B_0C0D,
+        cdab: 0x0A0B_0C0D,
+        badc: 0x0A0B_0C0D,
+        dcba: 0x0A0B_0C0D,
+    };
+
+    assert!(Registers::supports_raw_pointer_encoding());
+    assert_eq!(value.encode_be_to_raw_stack().to_vec(), value.to_be_bytes());
+    assert_eq!(value.encode_le_to_raw_stack().to_vec(), value.to_le_bytes());
+}
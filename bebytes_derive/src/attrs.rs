@@ -4,35 +4,337 @@ use std::vec::Vec;
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
-type AttributeData = (
-    Option<usize>,
-    Option<Vec<proc_macro2::Ident>>,
-    Option<crate::size_expr::SizeExpression>,
-    Option<u8>, // until_marker
-    Option<u8>, // after_marker
-);
-
 pub fn parse_attributes_with_expressions(
     attributes: &[syn::Attribute],
-    bits_attribute_present: &mut bool,
     errors: &mut Vec<proc_macro2::TokenStream>,
-) -> AttributeData {
+) -> crate::functional::AttributeData {
     match crate::functional::functional_attrs::parse_attributes_functional(attributes) {
-        Ok(attr_data) => {
-            *bits_attribute_present = attr_data.is_bits_attribute;
-            (
-                attr_data.size,
-                attr_data.field,
-                attr_data.size_expression,
-                attr_data.until_marker,
-                attr_data.after_marker,
-            )
-        }
+        Ok(attr_data) => attr_data,
         Err(errs) => {
             for e in errs {
                 errors.push(e.to_compile_error());
             }
-            (None, None, None, None, None)
+            crate::functional::AttributeData::default()
+        }
+    }
+}
+
+/// Struct-level options given through `#[bebytes(...)]` on the type itself
+#[derive(Debug, Default, Clone)]
+pub struct StructAttributes {
+    /// `#[bebytes(transparent)]`: single-field newtype encoded exactly like its inner type
+    pub transparent: bool,
+    /// `#[bebytes(bit_order = "lsb0")]`: layout of bit fields in both byte orders.
+    /// `msb0` is the big-endian layout and `lsb0` the little-endian one
+    pub bit_order: Option<crate::consts::Endianness>,
+    /// `#[bebytes(bits_struct)]`: a run of bit fields that a parent embeds in its own bit stream
+    pub bits_struct: bool,
+    /// `#[bebytes(c_layout)]`: fields padded like a C struct with native bit fields
+    pub c_layout: bool,
+    /// `#[bebytes(endian = "little")]`: byte order of `to_bytes` and `try_from_bytes`
+    pub endian: Option<crate::consts::Endianness>,
+}
+
+pub fn parse_struct_attributes(
+    attributes: &[syn::Attribute],
+) -> Result<StructAttributes, syn::Error> {
+    let mut struct_attrs = StructAttributes::default();
+
+    for attr in attributes {
+        if !attr.path().is_ident("bebytes") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("transparent") {
+                struct_attrs.transparent = true;
+                Ok(())
+            } else if meta.path.is_ident("bit_order") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                struct_attrs.bit_order = Some(match value.value().as_str() {
+                    "msb0" => crate::consts::Endianness::Big,
+                    "lsb0" => crate::consts::Endianness::Little,
+                    _ => {
+                        return Err(syn::Error::new(
+                            value.span(),
+                            "Invalid bit order. Expected \"msb0\" or \"lsb0\"",
+                        ))
+                    }
+                });
+                Ok(())
+            } else if meta.path.is_ident("bits_struct") {
+                struct_attrs.bits_struct = true;
+                Ok(())
+            } else if meta.path.is_ident("c_layout") {
+                struct_attrs.c_layout = true;
+                Ok(())
+            } else if meta.path.is_ident("endian") {
+                struct_attrs.endian = Some(parse_endian(&meta.value()?.parse()?)?);
+                Ok(())
+            } else {
+                Err(meta.error(
+                    "Unsupported bebytes attribute on struct. Expected `transparent`, `bit_order`, `bits_struct`, `c_layout` or `endian`",
+                ))
+            }
+        })?;
+    }
+
+    Ok(struct_attrs)
+}
+
+/// Byte order named by `endian = "big"` or `endian = "little"`
+pub fn parse_endian(value: &syn::LitStr) -> Result<crate::consts::Endianness, syn::Error> {
+    match value.value().as_str() {
+        "big" => Ok(crate::consts::Endianness::Big),
+        "little" => Ok(crate::consts::Endianness::Little),
+        _ => Err(syn::Error::new(
+            value.span(),
+            "Invalid endianness. Expected \"big\" or \"little\"",
+        )),
+    }
+}
+
+/// A transparent newtype must wrap exactly one field and must not change how it is encoded
+pub fn validate_transparent(fields: &syn::Fields) -> Result<(), syn::Error> {
+    if fields.len() != 1 {
+        return Err(syn::Error::new_spanned(
+            fields,
+            "#[bebytes(transparent)] requires a struct with exactly one field",
+        ));
+    }
+
+    let field = fields.iter().next().expect("checked above");
+    for attr in &field.attrs {
+        let path = attr.path();
+        if path.is_ident("bits")
+            || path.is_ident("With")
+            || path.is_ident("FromField")
+            || path.is_ident("UntilMarker")
+            || path.is_ident("AfterMarker")
+            || path.is_ident("size")
+        {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "Field attributes are not allowed on a #[bebytes(transparent)] newtype; it is encoded exactly like its inner type",
+            ));
         }
     }
+
+    Ok(())
+}
+
+/// Enum-level options for data-carrying enums
+#[derive(Debug, Default, Clone)]
+pub struct TaggedEnumAttributes {
+    /// `#[bebytes(tag_type = u16)]`: integer type of the leading tag
+    pub tag_type: Option<crate::enums::FlagType>,
+    /// `#[bebytes(tag_endian = "big")]`: fixed byte order of the tag, regardless of the method used
+    pub tag_endian: Option<crate::consts::Endianness>,
+}
+
+pub fn parse_tagged_enum_attributes(
+    attributes: &[syn::Attribute],
+) -> Result<TaggedEnumAttributes, syn::Error> {
+    let mut enum_attrs = TaggedEnumAttributes::default();
+
+    for attr in attributes {
+        if !attr.path().is_ident("bebytes") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("tag_type") {
+                let type_ident: syn::Ident = meta.value()?.parse()?;
+                enum_attrs.tag_type = Some(
+                    crate::enums::FlagType::from_ident(&type_ident).ok_or_else(|| {
+                        syn::Error::new(
+                            type_ident.span(),
+                            "Invalid tag type. Expected u8, u16, u32, u64, or u128",
+                        )
+                    })?,
+                );
+                Ok(())
+            } else if meta.path.is_ident("tag_endian") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                enum_attrs.tag_endian = Some(match value.value().as_str() {
+                    "big" => crate::consts::Endianness::Big,
+                    "little" => crate::consts::Endianness::Little,
+                    _ => {
+                        return Err(syn::Error::new(
+                            value.span(),
+                            "Invalid tag endianness. Expected \"big\" or \"little\"",
+                        ))
+                    }
+                });
+                Ok(())
+            } else {
+                Err(meta.error(
+                    "Unsupported bebytes attribute on data-carrying enum. Expected `tag_type` or `tag_endian`",
+                ))
+            }
+        })?;
+    }
+
+    Ok(enum_attrs)
+}
+
+/// Variant-level options for enums
+#[derive(Debug, Default, Clone)]
+pub struct VariantAttributes {
+    /// `#[bebytes(tag = N)]`: wire value that selects this variant
+    pub tag: Option<u128>,
+    /// `#[bebytes(other)]`: catch-all variant holding any unrecognized value
+    pub other: bool,
+    /// `#[bebytes(range = 0xF0..=0xFF)]`: inclusive bounds of the values held by this variant
+    pub range: Option<(i128, i128)>,
+}
+
+pub fn parse_variant_attributes(
+    attributes: &[syn::Attribute],
+) -> Result<VariantAttributes, syn::Error> {
+    let mut variant_attrs = VariantAttributes::default();
+
+    for attr in attributes {
+        if !attr.path().is_ident("bebytes") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("tag") {
+                let value: syn::LitInt = meta.value()?.parse()?;
+                variant_attrs.tag = Some(value.base10_parse()?);
+                Ok(())
+            } else if meta.path.is_ident("other") {
+                variant_attrs.other = true;
+                Ok(())
+            } else if meta.path.is_ident("range") {
+                let range: syn::ExprRange = meta.value()?.parse()?;
+                variant_attrs.range = Some(parse_discriminant_range(&range)?);
+                Ok(())
+            } else {
+                Err(meta.error(
+                    "Unsupported bebytes attribute on enum variant. Expected `tag`, `other` or `range`",
+                ))
+            }
+        })?;
+    }
+
+    Ok(variant_attrs)
+}
+
+/// Inclusive bounds of `start..end` or `start..=end`
+fn parse_discriminant_range(range: &syn::ExprRange) -> Result<(i128, i128), syn::Error> {
+    let bound = |expr: &Option<Box<syn::Expr>>| {
+        expr.as_deref()
+            .and_then(crate::enums::evaluate_discriminant)
+            .ok_or_else(|| {
+                syn::Error::new_spanned(
+                    range,
+                    "Range bounds must be integer, byte or char literals, e.g. `0xF0..=0xFF`",
+                )
+            })
+    };
+    let start = bound(&range.start)?;
+    let end = match range.limits {
+        syn::RangeLimits::Closed(_) => bound(&range.end)?,
+        syn::RangeLimits::HalfOpen(_) => bound(&range.end)?.wrapping_sub(1),
+    };
+    if start > end {
+        return Err(syn::Error::new_spanned(range, "Range must not be empty"));
+    }
+    Ok((start, end))
+}
+
+/// Width given by `#[bebytes(bytes = N)]`, for code that only needs a field's wire size.
+/// Malformed attributes are reported when the field itself is processed
+pub fn parse_byte_width(attributes: &[syn::Attribute]) -> Option<usize> {
+    let mut width = None;
+    for attr in attributes {
+        if !attr.path().is_ident("bebytes") {
+            continue;
+        }
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("bytes") {
+                let value: syn::LitInt = meta.value()?.parse()?;
+                width = value.base10_parse().ok();
+            } else if meta.input.peek(syn::Token![=]) {
+                let _: syn::Expr = meta.value()?.parse()?;
+            } else if meta.input.peek(syn::token::Paren) {
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        });
+    }
+    width
+}
+
+/// Fixed byte order of a field, from `#[bebytes(endian = "little")]` or the
+/// `#[bebytes(big_endian)]` and `#[bebytes(little_endian)]` shorthands
+pub fn parse_field_endian(attributes: &[syn::Attribute]) -> Option<crate::consts::Endianness> {
+    let mut endian = None;
+    for attr in attributes {
+        if !attr.path().is_ident("bebytes") {
+            continue;
+        }
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("endian") {
+                endian = parse_endian(&meta.value()?.parse()?).ok();
+            } else if meta.path.is_ident("big_endian") {
+                endian = Some(crate::consts::Endianness::Big);
+            } else if meta.path.is_ident("little_endian") {
+                endian = Some(crate::consts::Endianness::Little);
+            } else if meta.input.peek(syn::Token![=]) {
+                let _: syn::Expr = meta.value()?.parse()?;
+            } else if meta.input.peek(syn::token::Paren) {
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        });
+    }
+    endian
+}
+
+/// Word order of a field, from `#[bebytes(word_order = "CDAB")]`
+pub fn parse_field_word_order(
+    attributes: &[syn::Attribute],
+) -> Option<crate::word_order::WordOrder> {
+    let mut word_order = None;
+    for attr in attributes {
+        if !attr.path().is_ident("bebytes") {
+            continue;
+        }
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("word_order") {
+                word_order = crate::word_order::WordOrder::from_lit(&meta.value()?.parse()?).ok();
+            } else if meta.input.peek(syn::Token![=]) {
+                let _: syn::Expr = meta.value()?.parse()?;
+            } else if meta.input.peek(syn::token::Paren) {
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        });
+    }
+    word_order
+}
+
+/// Integer type named by `#[repr(..)]`, if it has a fixed width
+pub fn parse_repr_type(attributes: &[syn::Attribute]) -> Option<crate::enums::FlagType> {
+    let mut repr = None;
+    for attr in attributes {
+        if !attr.path().is_ident("repr") {
+            continue;
+        }
+        // Other repr hints such as `C` or `align(N)` are not our concern
+        let _ = attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident() {
+                repr = repr.or_else(|| crate::enums::FlagType::from_int_ident(ident));
+            }
+            if meta.input.peek(syn::token::Paren) {
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        });
+    }
+    repr
 }
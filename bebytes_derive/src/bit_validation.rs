@@ -1,12 +1,282 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use core::ops::Range;
 use proc_macro2::TokenStream;
 
-pub fn validate_byte_completeness(fields: &syn::FieldsNamed) -> Result<(), TokenStream> {
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Where a `#[bits(a..b)]` field sits inside its group of positioned bit fields.
+/// Bit 0 is the least significant bit of the group, as in register diagrams
+pub struct BitPlacement {
+    pub range: Range<usize>,
+    /// Bits taken by the whole group, rounded up to full bytes
+    pub group_width: usize,
+    pub first_in_group: bool,
+    pub last_in_group: bool,
+}
+
+impl BitPlacement {
+    /// Offset of the field from the start of the group in the bit stream. Big-endian
+    /// streams start at the most significant bit, little-endian streams at the least
+    pub fn stream_offset(&self, endianness: crate::consts::Endianness) -> usize {
+        match endianness {
+            crate::consts::Endianness::Big => self.group_width - self.range.end,
+            crate::consts::Endianness::Little => self.range.start,
+        }
+    }
+}
+
+/// Parses the `a..b` or `a..=b` of a positioned `#[bits(..)]` attribute.
+/// `None` when the attribute holds a bit count instead
+pub fn parse_bit_range(tokens: &TokenStream) -> Result<Option<Range<usize>>, syn::Error> {
+    let Ok(syn::Expr::Range(range)) = syn::parse2::<syn::Expr>(tokens.clone()) else {
+        return Ok(None);
+    };
+    let bound = |expr: Option<&syn::Expr>| match expr {
+        Some(syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        })) => lit.base10_parse::<usize>(),
+        _ => Err(syn::Error::new_spanned(
+            &range,
+            "Bit ranges need two integer bounds, e.g. #[bits(12..16)]",
+        )),
+    };
+    let start = bound(range.start.as_deref())?;
+    let mut end = bound(range.end.as_deref())?;
+    if matches!(range.limits, syn::RangeLimits::Closed(_)) {
+        end += 1;
+    }
+    if start >= end {
+        return Err(syn::Error::new_spanned(
+            &range,
+            "bits attribute must specify at least 1 bit",
+        ));
+    }
+    Ok(Some(start..end))
+}
+
+/// Groups consecutive `#[bits(a..b)]` fields and checks that their ranges don't overlap.
+/// Bits of a group that no field covers are reserved: written as zero and ignored on read
+pub fn bit_placements(fields: &syn::Fields) -> Result<Vec<Option<BitPlacement>>, syn::Error> {
+    let mut placements: Vec<Option<BitPlacement>> = Vec::new();
+    let mut group: Vec<(usize, &syn::Field, &syn::Attribute, Range<usize>)> = Vec::new();
+
+    for (index, field) in fields.iter().enumerate() {
+        let mut range = None;
+        for attr in &field.attrs {
+            if let (true, syn::Meta::List(list)) = (attr.path().is_ident("bits"), &attr.meta) {
+                if let Some(bits) = parse_bit_range(&list.tokens)? {
+                    if matches!(field.ty, syn::Type::Array(_)) {
+                        return Err(syn::Error::new_spanned(
+                            attr,
+                            "Bit ranges apply to single fields. Use #[bits(N)] on arrays",
+                        ));
+                    }
+                    range = Some((attr, bits));
+                }
+            }
+        }
+        placements.push(None);
+        match range {
+            Some((attr, bits)) => group.push((index, field, attr, bits)),
+            None => close_group(&mut group, &mut placements)?,
+        }
+    }
+    close_group(&mut group, &mut placements)?;
+
+    Ok(placements)
+}
+
+fn close_group(
+    group: &mut Vec<(usize, &syn::Field, &syn::Attribute, Range<usize>)>,
+    placements: &mut [Option<BitPlacement>],
+) -> Result<(), syn::Error> {
+    for (position, (index, field, attr, bits)) in group.iter().enumerate() {
+        if let Some((other_index, other, _, other_bits)) =
+            group[..position].iter().find(|(_, _, _, other_bits)| {
+                bits.start < other_bits.end && other_bits.start < bits.end
+            })
+        {
+            return Err(syn::Error::new_spanned(
+                attr,
+                format!(
+                    "Bits {}..{} of {} overlap bits {}..{} of {}",
+                    bits.start,
+                    bits.end,
+                    field_label(*index, field),
+                    other_bits.start,
+                    other_bits.end,
+                    field_label(*other_index, other),
+                ),
+            ));
+        }
+    }
+
+    let group_width = group
+        .iter()
+        .map(|(_, _, _, bits)| bits.end)
+        .max()
+        .unwrap_or(0)
+        .div_ceil(8)
+        * 8;
+    let last = group.len().saturating_sub(1);
+    for (position, (index, _, _, bits)) in group.drain(..).enumerate() {
+        placements[index] = Some(BitPlacement {
+            range: bits,
+            group_width,
+            first_in_group: position == 0,
+            last_in_group: position == last,
+        });
+    }
+    Ok(())
+}
+
+fn field_label(index: usize, field: &syn::Field) -> String {
+    field
+        .ident
+        .as_ref()
+        .map_or_else(|| format!("field {index}"), ToString::to_string)
+}
+
+/// Padding of a `#[bebytes(c_layout)]` struct, following the System V bit-field rules
+/// of GCC and Clang
+pub struct CLayout {
+    /// Padding bits before each field
+    pub padding: Vec<usize>,
+    /// Padding bits after the last field, up to the alignment of the struct
+    pub tail_padding: usize,
+    /// Size of the struct in bytes
+    pub size: usize,
+}
+
+/// Lays the fields out like a C compiler: a bit field starts in the next storage unit of
+/// its declared type when it would straddle one, other fields are aligned to their type,
+/// and the struct is padded to its largest alignment
+pub fn c_layout(fields: &syn::Fields) -> Result<CLayout, syn::Error> {
+    let mut padding = Vec::new();
+    let mut position = 0;
+    let mut alignment = 1;
+
+    for field in fields {
+        let field_alignment = c_alignment(&field.ty)?;
+        let (start, width) = match c_bit_field_width(field)? {
+            Some(width) => {
+                let unit = 8 * field_alignment;
+                let start = if position % unit + width > unit {
+                    position.div_ceil(unit) * unit
+                } else {
+                    position
+                };
+                (start, width)
+            }
+            None => (
+                position.div_ceil(8 * field_alignment) * 8 * field_alignment,
+                8 * crate::utils::get_fixed_type_size(&field.ty).unwrap_or(0),
+            ),
+        };
+        padding.push(start - position);
+        position = start + width;
+        alignment = alignment.max(field_alignment);
+    }
+
+    let size = position.div_ceil(8 * alignment) * alignment;
+    Ok(CLayout {
+        padding,
+        tail_padding: 8 * size - position,
+        size,
+    })
+}
+
+/// Width of a `#[bits(N)]` field of a `c_layout` struct, `None` for other fields
+fn c_bit_field_width(field: &syn::Field) -> Result<Option<usize>, syn::Error> {
+    let mut width = None;
+    for attr in &field.attrs {
+        let path = attr.path();
+        if path.is_ident("bits") {
+            let syn::Meta::List(list) = &attr.meta else {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "Invalid bits attribute format",
+                ));
+            };
+            if parse_bit_range(&list.tokens)?.is_some() || list.tokens.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "c_layout bit fields take a width, e.g. #[bits(3)]",
+                ));
+            }
+            let bits = attr.parse_args::<syn::LitInt>()?.base10_parse::<usize>()?;
+            if !matches!(&field.ty, syn::Type::Path(tp) if crate::utils::is_primitive_type(tp))
+                || crate::utils::get_primitive_type_max_bits(&field.ty).is_err()
+                || matches!(&field.ty, syn::Type::Path(tp) if tp.path.is_ident("char"))
+            {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "c_layout bit fields must be integers or bool",
+                ));
+            }
+            width = Some(bits);
+        } else if path.is_ident("With")
+            || path.is_ident("FromField")
+            || path.is_ident("bebytes")
+            || path.is_ident("UntilMarker")
+            || path.is_ident("AfterMarker")
+        {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "Fields of a c_layout struct only take #[bits(N)]",
+            ));
+        }
+    }
+    Ok(width)
+}
+
+/// Alignment in bytes of a field type in a `c_layout` struct, the same as its size for
+/// primitives and the element's alignment for arrays
+fn c_alignment(ty: &syn::Type) -> Result<usize, syn::Error> {
+    match ty {
+        syn::Type::Path(tp) if crate::utils::is_primitive_type(tp) => {
+            crate::utils::get_primitive_type_size(ty)
+        }
+        syn::Type::Array(array) if crate::utils::array_literal_length(array).is_some() => {
+            c_alignment(&array.elem)
+        }
+        _ => Err(syn::Error::new_spanned(
+            ty,
+            "c_layout fields must be primitives or arrays of primitives with a literal length",
+        )),
+    }
+}
+
+pub fn validate_byte_completeness(fields: &syn::Fields) -> Result<(), TokenStream> {
+    let placements = bit_placements(fields).map_err(|e| e.to_compile_error())?;
     let mut total_bits = 0;
     let mut has_auto_sized = false;
 
-    for field in &fields.named {
+    for (field, placement) in fields.iter().zip(&placements) {
+        // A positioned group takes its whole width, counted once at its first field
+        if let Some(placement) = placement {
+            if placement.first_in_group {
+                if total_bits % 8 != 0 {
+                    return Err(syn::Error::new_spanned(
+                        field,
+                        format!(
+                            "Positioned bit fields must start on a byte boundary. The bit fields before them leave {} bits of a byte",
+                            total_bits % 8
+                        ),
+                    )
+                    .to_compile_error());
+                }
+                total_bits += placement.group_width;
+            }
+            continue;
+        }
+
         for attr in &field.attrs {
             if attr.path().is_ident("bits") {
                 // Parse #[bits(N)] where N is the size
@@ -32,7 +302,21 @@ pub fn validate_byte_completeness(fields: &syn::FieldsNamed) -> Result<(), Token
                                         )
                                         .to_compile_error());
                                     }
-                                    total_bits += n;
+                                    // Arrays pack every element in `n` bits
+                                    let elements = match &field.ty {
+                                        syn::Type::Array(arr) => {
+                                            // A length that isn't a literal is reported
+                                            // with the field's other errors
+                                            let Some(length) =
+                                                crate::utils::array_literal_length(arr)
+                                            else {
+                                                return Ok(());
+                                            };
+                                            length
+                                        }
+                                        _ => 1,
+                                    };
+                                    total_bits += n * elements;
                                 }
                                 Err(e) => return Err(e.to_compile_error()),
                             },
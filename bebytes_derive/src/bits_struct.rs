@@ -0,0 +1,319 @@
+//! `#[bebytes(bits_struct)]`: runs of bit fields embedded in a parent's bit stream
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::spanned::Spanned;
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// How a field of a bits struct is converted to and from its bits
+enum BitsKind {
+    Unsigned,
+    /// Signed integer of this many bits, sign-extended when unpacked
+    Signed(usize),
+    Bool,
+    /// Another bits struct, packed in the same bit order
+    Nested,
+}
+
+struct BitsField<'a> {
+    binding: syn::Ident,
+    member: syn::Member,
+    ty: &'a syn::Type,
+    size: usize,
+    kind: BitsKind,
+}
+
+/// Generates the `BitsStruct` impl and the `new` constructor of a bits struct
+pub fn generate_bits_struct(
+    name: &syn::Ident,
+    generics: &syn::Generics,
+    fields: &syn::Fields,
+) -> Result<TokenStream, syn::Error> {
+    let bits_fields = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| analyze_field(index, field))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let total_bits: usize = bits_fields.iter().map(|field| field.size).sum();
+    if total_bits > 128 {
+        return Err(syn::Error::new_spanned(
+            fields,
+            format!("A bits_struct holds at most 128 bits, but its fields take {total_bits}"),
+        ));
+    }
+
+    let mut msb0_shifts = Vec::new();
+    let mut lsb0_shifts = Vec::new();
+    let mut offset = 0;
+    for field in &bits_fields {
+        lsb0_shifts.push(offset);
+        msb0_shifts.push(total_bits - offset - field.size);
+        offset += field.size;
+    }
+
+    let constructor = crate::utils::fields_constructor(&quote! { Self }, fields);
+    let constructor_args = bits_fields.iter().map(|field| {
+        let binding = &field.binding;
+        let ty = field.ty;
+        quote! { #binding: #ty }
+    });
+    let limit_checks = bits_fields.iter().map(limit_check);
+
+    let big_endian_methods = conversion_methods(
+        &bits_fields,
+        &msb0_shifts,
+        &quote! { to_msb0_bits },
+        &quote! { from_msb0_bits },
+        &constructor,
+    );
+    let little_endian_methods = conversion_methods(
+        &bits_fields,
+        &lsb0_shifts,
+        &quote! { to_lsb0_bits },
+        &quote! { from_lsb0_bits },
+        &constructor,
+    );
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::bebytes::BitsStruct for #name #ty_generics #where_clause {
+            const BITS: usize = #total_bits;
+
+            #big_endian_methods
+
+            #little_endian_methods
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[allow(clippy::too_many_arguments)]
+            pub fn new(#(#constructor_args,)*) -> Self {
+                #(#limit_checks)*
+                #constructor
+            }
+        }
+    })
+}
+
+fn analyze_field(index: usize, field: &syn::Field) -> Result<BitsField<'_>, syn::Error> {
+    let mut size = None;
+    for attr in &field.attrs {
+        let path = attr.path();
+        if path.is_ident("bits") {
+            if let syn::Meta::List(list) = &attr.meta {
+                if crate::bit_validation::parse_bit_range(&list.tokens)?.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        "Bit ranges are not supported in a bits_struct. Use #[bits(N)]",
+                    ));
+                }
+            }
+            size = crate::functional::functional_attrs::parse_bits_attribute_functional(attr)?;
+        } else if path.is_ident("With")
+            || path.is_ident("FromField")
+            || path.is_ident("bebytes")
+            || path.is_ident("UntilMarker")
+            || path.is_ident("AfterMarker")
+        {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "Fields of a bits_struct only take #[bits(N)]",
+            ));
+        }
+    }
+    let size = match size {
+        Some(0) => {
+            return Err(syn::Error::new_spanned(
+                field,
+                "bits attribute must specify at least 1 bit",
+            ))
+        }
+        Some(size) => size,
+        None => {
+            return Err(syn::Error::new(
+                field.span(),
+                "Every field of a bits_struct needs #[bits(N)]",
+            ))
+        }
+    };
+
+    let kind = match &field.ty {
+        syn::Type::Path(tp) if tp.path.is_ident("bool") => BitsKind::Bool,
+        syn::Type::Path(tp) if tp.path.is_ident("char") => {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                "Fields of a bits_struct are integers, bool or other bits_struct types",
+            ))
+        }
+        syn::Type::Path(tp) if crate::utils::is_primitive_type(tp) => {
+            match tp
+                .path
+                .get_ident()
+                .and_then(crate::enums::FlagType::from_int_ident)
+            {
+                Some(int_type) if int_type.is_signed() => {
+                    BitsKind::Signed(8 * int_type.byte_size())
+                }
+                Some(_) => BitsKind::Unsigned,
+                None => {
+                    return Err(syn::Error::new_spanned(
+                        &field.ty,
+                        "Fields of a bits_struct are integers, bool or other bits_struct types",
+                    ))
+                }
+            }
+        }
+        syn::Type::Path(_) => BitsKind::Nested,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                "Fields of a bits_struct are integers, bool or other bits_struct types",
+            ))
+        }
+    };
+    if !matches!(kind, BitsKind::Nested) {
+        let max_bits = crate::utils::get_primitive_type_max_bits(&field.ty)?;
+        if size > max_bits {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!(
+                    "bits attribute specifies {size} bits, but type can only hold {max_bits} bits"
+                ),
+            ));
+        }
+    }
+
+    Ok(BitsField {
+        binding: crate::utils::field_binding(index, field),
+        member: crate::utils::field_member(index, field),
+        ty: &field.ty,
+        size,
+        kind,
+    })
+}
+
+/// The packing method `to_bits` and the unpacking method `from_bits` for one bit order
+fn conversion_methods(
+    fields: &[BitsField],
+    shifts: &[usize],
+    to_bits: &TokenStream,
+    from_bits: &TokenStream,
+    constructor: &TokenStream,
+) -> TokenStream {
+    let packed = pack_fields(fields, shifts, to_bits);
+    let unpacked = unpack_fields(fields, shifts, from_bits);
+    quote! {
+        fn #to_bits(&self) -> u128 {
+            #packed
+        }
+
+        fn #from_bits(bits: u128) -> Self {
+            #unpacked
+            #constructor
+        }
+    }
+}
+
+fn mask(size: usize) -> u128 {
+    if size >= 128 {
+        u128::MAX
+    } else {
+        (1 << size) - 1
+    }
+}
+
+/// Panics like the other bit fields when a value doesn't fit in its bits
+fn limit_check(field: &BitsField) -> TokenStream {
+    match field.kind {
+        BitsKind::Unsigned => {
+            let max_bits = crate::utils::get_primitive_type_max_bits(field.ty).unwrap_or(128);
+            if field.size >= max_bits {
+                quote! {}
+            } else {
+                crate::functional::pure_helpers::create_bit_field_limit_check(
+                    &field.binding,
+                    field.ty,
+                    field.size,
+                )
+            }
+        }
+        BitsKind::Signed(_) => crate::functional::pure_helpers::create_bit_field_limit_check(
+            &field.binding,
+            field.ty,
+            field.size,
+        ),
+        BitsKind::Bool | BitsKind::Nested => quote! {},
+    }
+}
+
+fn pack_fields(fields: &[BitsField], shifts: &[usize], to_bits: &TokenStream) -> TokenStream {
+    let packed = fields.iter().zip(shifts).map(|(field, shift)| {
+        let binding = &field.binding;
+        let member = &field.member;
+        let ty = field.ty;
+        let size = field.size;
+        let mask = mask(size);
+        let (access, value) = match field.kind {
+            BitsKind::Unsigned | BitsKind::Bool => (
+                quote! { let #binding = self.#member; },
+                quote! { u128::from(#binding) },
+            ),
+            BitsKind::Signed(_) => (
+                quote! { let #binding = self.#member; },
+                quote! { (#binding as u128) & #mask },
+            ),
+            BitsKind::Nested => (
+                quote! {
+                    let () = ::bebytes::BitsStructWidth::<#ty, #size>::CHECK;
+                    let #binding = &self.#member;
+                },
+                quote! { ::bebytes::BitsStruct::#to_bits(#binding) },
+            ),
+        };
+        let check = limit_check(field);
+        quote! {
+            {
+                #access
+                #check
+                bits |= (#value) << #shift;
+            }
+        }
+    });
+    quote! {
+        let mut bits = 0u128;
+        #(#packed)*
+        bits
+    }
+}
+
+fn unpack_fields(fields: &[BitsField], shifts: &[usize], from_bits: &TokenStream) -> TokenStream {
+    let unpacked = fields.iter().zip(shifts).map(|(field, shift)| {
+        let binding = &field.binding;
+        let ty = field.ty;
+        let size = field.size;
+        let mask = mask(size);
+        let value = match field.kind {
+            BitsKind::Signed(type_bits) if size < type_bits => {
+                let extend = type_bits - size;
+                quote! { ((raw as #ty) << #extend) >> #extend }
+            }
+            BitsKind::Unsigned | BitsKind::Signed(_) => quote! { raw as #ty },
+            BitsKind::Bool => quote! { raw != 0 },
+            BitsKind::Nested => quote! {
+                <#ty as ::bebytes::BitsStruct>::#from_bits(raw)
+            },
+        };
+        quote! {
+            let #binding = {
+                let raw = (bits >> #shift) & #mask;
+                #value
+            };
+        }
+    });
+    quote! { #(#unpacked)* }
+}
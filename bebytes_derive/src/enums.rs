@@ -6,6 +6,10 @@ use std::vec::Vec;
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
+/// Integer type an enum is encoded as on the wire.
+///
+/// Flag enums and tags only use the unsigned types; signed types encode unit enums with
+/// negative discriminants.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FlagType {
     U8,
@@ -13,6 +17,11 @@ pub enum FlagType {
     U32,
     U64,
     U128,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
 }
 
 impl FlagType {
@@ -30,26 +39,72 @@ impl FlagType {
         }
     }
 
-    pub fn byte_size(self) -> usize {
+    /// Smallest signed type holding every value in `min..=max`
+    pub fn signed_from_range(min: i128, max: i128) -> Self {
+        [FlagType::I8, FlagType::I16, FlagType::I32, FlagType::I64]
+            .into_iter()
+            .find(|ty| ty.contains(min) && ty.contains(max))
+            .unwrap_or(FlagType::I128)
+    }
+
+    /// Parses an unsigned integer type name
+    pub fn from_ident(ident: &syn::Ident) -> Option<Self> {
+        match ident.to_string().as_str() {
+            "u8" => Some(FlagType::U8),
+            "u16" => Some(FlagType::U16),
+            "u32" => Some(FlagType::U32),
+            "u64" => Some(FlagType::U64),
+            "u128" => Some(FlagType::U128),
+            _ => None,
+        }
+    }
+
+    /// Parses a fixed-width integer type name, signed or unsigned
+    pub fn from_int_ident(ident: &syn::Ident) -> Option<Self> {
+        match ident.to_string().as_str() {
+            "i8" => Some(FlagType::I8),
+            "i16" => Some(FlagType::I16),
+            "i32" => Some(FlagType::I32),
+            "i64" => Some(FlagType::I64),
+            "i128" => Some(FlagType::I128),
+            _ => Self::from_ident(ident),
+        }
+    }
+
+    pub fn is_signed(self) -> bool {
+        matches!(
+            self,
+            FlagType::I8 | FlagType::I16 | FlagType::I32 | FlagType::I64 | FlagType::I128
+        )
+    }
+
+    /// Unsigned type of the same width
+    pub fn unsigned(self) -> Self {
         match self {
-            FlagType::U8 => 1,
-            FlagType::U16 => 2,
-            FlagType::U32 => 4,
-            FlagType::U64 => 8,
-            FlagType::U128 => 16,
+            FlagType::I8 => FlagType::U8,
+            FlagType::I16 => FlagType::U16,
+            FlagType::I32 => FlagType::U32,
+            FlagType::I64 => FlagType::U64,
+            FlagType::I128 => FlagType::U128,
+            unsigned => unsigned,
         }
     }
 
-    pub fn type_tokens(self) -> proc_macro2::TokenStream {
+    pub fn byte_size(self) -> usize {
         match self {
-            FlagType::U8 => quote! { u8 },
-            FlagType::U16 => quote! { u16 },
-            FlagType::U32 => quote! { u32 },
-            FlagType::U64 => quote! { u64 },
-            FlagType::U128 => quote! { u128 },
+            FlagType::U8 | FlagType::I8 => 1,
+            FlagType::U16 | FlagType::I16 => 2,
+            FlagType::U32 | FlagType::I32 => 4,
+            FlagType::U64 | FlagType::I64 => 8,
+            FlagType::U128 | FlagType::I128 => 16,
         }
     }
 
+    pub fn type_tokens(self) -> proc_macro2::TokenStream {
+        let ident = syn::Ident::new(self.type_name(), proc_macro2::Span::call_site());
+        quote! { #ident }
+    }
+
     pub fn max_value(self) -> u128 {
         match self {
             FlagType::U8 => u128::from(u8::MAX),
@@ -57,9 +112,73 @@ impl FlagType {
             FlagType::U32 => u128::from(u32::MAX),
             FlagType::U64 => u128::from(u64::MAX),
             FlagType::U128 => u128::MAX,
+            signed => signed.unsigned().max_value() >> 1,
+        }
+    }
+
+    /// Whether `value` is representable in this type
+    #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    pub fn contains(self, value: i128) -> bool {
+        if self.is_signed() {
+            let max = self.max_value() as i128;
+            (-max - 1..=max).contains(&value)
+        } else {
+            value >= 0 && value as u128 <= self.max_value()
+        }
+    }
+
+    /// Suffixed integer literal of this type, e.g. `7u16`.
+    ///
+    /// `value` is truncated to the type's width, so negative values can be passed as their
+    /// two's complement bit pattern.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub fn literal(self, value: u128) -> proc_macro2::Literal {
+        match self {
+            FlagType::U8 => proc_macro2::Literal::u8_suffixed(value as u8),
+            FlagType::U16 => proc_macro2::Literal::u16_suffixed(value as u16),
+            FlagType::U32 => proc_macro2::Literal::u32_suffixed(value as u32),
+            FlagType::U64 => proc_macro2::Literal::u64_suffixed(value as u64),
+            FlagType::U128 => proc_macro2::Literal::u128_suffixed(value),
+            FlagType::I8 => proc_macro2::Literal::i8_suffixed(value as i8),
+            FlagType::I16 => proc_macro2::Literal::i16_suffixed(value as i16),
+            FlagType::I32 => proc_macro2::Literal::i32_suffixed(value as i32),
+            FlagType::I64 => proc_macro2::Literal::i64_suffixed(value as i64),
+            FlagType::I128 => proc_macro2::Literal::i128_suffixed(value as i128),
         }
     }
 
+    /// Expression reading a value of this type from the start of `bytes`
+    pub fn read_tokens(self, endianness: crate::consts::Endianness) -> proc_macro2::TokenStream {
+        let type_tokens = self.type_tokens();
+        let byte_size = self.byte_size();
+        let from_bytes_method = crate::utils::get_from_bytes_method(endianness);
+        quote! {
+            {
+                let mut value_bytes = [0u8; #byte_size];
+                value_bytes.copy_from_slice(&bytes[..#byte_size]);
+                #type_tokens::#from_bytes_method(value_bytes)
+            }
+        }
+    }
+
+    /// Call writing `val` of this type to `buf`, a mutable reference to a `BufMut`
+    pub fn put_tokens(
+        self,
+        endianness: crate::consts::Endianness,
+        buf: &proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        if self.is_signed() {
+            let to_bytes_method = crate::utils::get_to_bytes_method(endianness);
+            return quote! { ::bebytes::BufMut::put_slice(#buf, &val.#to_bytes_method()) };
+        }
+        let suffix = match endianness {
+            crate::consts::Endianness::Little if self.byte_size() > 1 => "_le",
+            _ => "",
+        };
+        let put_method = quote::format_ident!("put_{}{}", self.type_name(), suffix);
+        quote! { ::bebytes::BufMut::#put_method(#buf, val) }
+    }
+
     pub fn type_name(self) -> &'static str {
         match self {
             FlagType::U8 => "u8",
@@ -67,6 +186,117 @@ impl FlagType {
             FlagType::U32 => "u32",
             FlagType::U64 => "u64",
             FlagType::U128 => "u128",
+            FlagType::I8 => "i8",
+            FlagType::I16 => "i16",
+            FlagType::I32 => "i32",
+            FlagType::I64 => "i64",
+            FlagType::I128 => "i128",
+        }
+    }
+}
+
+/// How the generated flag set decodes bits that no variant defines,
+/// chosen with `#[bebytes(flags, unknown_bits = "...")]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnknownBits {
+    /// Fail with `InvalidDiscriminant` / `InvalidDiscriminantLarge`
+    #[default]
+    Reject,
+    /// Keep them, so they are re-encoded unchanged
+    Keep,
+    /// Clear them
+    Mask,
+}
+
+impl UnknownBits {
+    pub fn from_lit(lit: &syn::LitStr) -> Result<Self, syn::Error> {
+        match lit.value().as_str() {
+            "reject" => Ok(UnknownBits::Reject),
+            // `retain` and `truncate` follow the naming of the `bitflags` crate
+            "keep" | "retain" => Ok(UnknownBits::Keep),
+            "mask" | "truncate" => Ok(UnknownBits::Mask),
+            _ => Err(syn::Error::new(
+                lit.span(),
+                "Invalid unknown_bits policy. Expected \"reject\", \"keep\" (or \"retain\") or \"mask\" (or \"truncate\")",
+            )),
+        }
+    }
+}
+
+/// Evaluates a discriminant expression made of integer, byte and char literals, casts and
+/// arithmetic. Returns `None` for anything else, such as paths to constants.
+#[allow(clippy::cast_possible_wrap)]
+pub fn evaluate_discriminant(expr: &syn::Expr) -> Option<i128> {
+    match expr {
+        syn::Expr::Lit(expr_lit) => match &expr_lit.lit {
+            syn::Lit::Int(token) => token
+                .base10_parse::<i128>()
+                .ok()
+                // Values above i128::MAX only fit a u128 repr and keep their bit pattern
+                .or_else(|| token.base10_parse::<u128>().ok().map(|value| value as i128)),
+            syn::Lit::Byte(byte) => Some(i128::from(byte.value())),
+            syn::Lit::Char(ch) => Some(i128::from(u32::from(ch.value()))),
+            _ => None,
+        },
+        syn::Expr::Paren(paren) => evaluate_discriminant(&paren.expr),
+        syn::Expr::Group(group) => evaluate_discriminant(&group.expr),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => evaluate_discriminant(expr)?.checked_neg(),
+        syn::Expr::Cast(cast) => {
+            let value = evaluate_discriminant(&cast.expr)?;
+            let syn::Type::Path(type_path) = cast.ty.as_ref() else {
+                return None;
+            };
+            match type_path.path.get_ident()?.to_string().as_str() {
+                // Discriminants are evaluated in `isize` unless the enum has a repr
+                "isize" | "usize" | "i128" | "u128" => Some(value),
+                _ => {
+                    let ty = FlagType::from_int_ident(type_path.path.get_ident()?)?;
+                    ty.contains(value).then_some(value)
+                }
+            }
+        }
+        syn::Expr::Binary(binary) => {
+            let left = evaluate_discriminant(&binary.left)?;
+            let right = evaluate_discriminant(&binary.right)?;
+            match binary.op {
+                syn::BinOp::Add(_) => left.checked_add(right),
+                syn::BinOp::Sub(_) => left.checked_sub(right),
+                syn::BinOp::Mul(_) => left.checked_mul(right),
+                syn::BinOp::Shl(_) => left.checked_shl(u32::try_from(right).ok()?),
+                syn::BinOp::Shr(_) => left.checked_shr(u32::try_from(right).ok()?),
+                syn::BinOp::BitOr(_) => Some(left | right),
+                syn::BinOp::BitAnd(_) => Some(left & right),
+                syn::BinOp::BitXor(_) => Some(left ^ right),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Discriminant of a unit variant, as far as the macro can tell
+#[derive(Clone)]
+enum Discriminant {
+    Known(i128),
+    /// `base + offset`, where `base` is only known to the compiler (e.g. a constant)
+    Deferred {
+        base: syn::Expr,
+        offset: u128,
+    },
+}
+
+impl Discriminant {
+    fn next(&self) -> Self {
+        match self {
+            Discriminant::Known(value) => Discriminant::Known(value.wrapping_add(1)),
+            Discriminant::Deferred { base, offset } => Discriminant::Deferred {
+                base: base.clone(),
+                offset: offset + 1,
+            },
         }
     }
 }
@@ -80,21 +310,91 @@ type EnumHandleResult = (
     Vec<(syn::Ident, u128)>,
     Vec<proc_macro2::TokenStream>, // errors
     FlagType,
+    Option<syn::Ident>,            // #[bebytes(other)] catch-all variant
+    Vec<proc_macro2::TokenStream>, // range checks of discriminants only the compiler evaluates
 );
 
-#[allow(clippy::too_many_lines)]
+/// Whether a variant holds the raw value through `#[bebytes(other)]` or `#[bebytes(range = ..)]`.
+/// Attribute errors are reported by `handle_enum`.
+fn is_raw_value_variant(variant: &syn::Variant) -> bool {
+    crate::attrs::parse_variant_attributes(&variant.attrs)
+        .is_ok_and(|variant_attrs| variant_attrs.other || variant_attrs.range.is_some())
+}
+
+#[allow(clippy::too_many_lines, clippy::cast_sign_loss)]
 pub fn handle_enum(
     mut errors: Vec<proc_macro2::TokenStream>,
     data_enum: syn::DataEnum,
     explicit_flag_type: Option<FlagType>,
+    repr: Option<FlagType>,
 ) -> EnumHandleResult {
     let variants = data_enum.variants;
-    let values = variants
+    let mut other_variant: Option<syn::Ident> = None;
+    let mut range_variants: Vec<(syn::Ident, i128, i128)> = Vec::new();
+    // Implicit discriminants continue from the previous variant, as in Rust
+    let mut next_discriminant = Discriminant::Known(0);
+    let discriminants = variants
         .iter()
-        .enumerate()
-        .map(|(index, variant)| {
+        .filter_map(|variant| {
             let ident = &variant.ident;
 
+            let discriminant = match &variant.discriminant {
+                Some((_, expr)) => evaluate_discriminant(expr).map_or_else(
+                    || Discriminant::Deferred {
+                        base: expr.clone(),
+                        offset: 0,
+                    },
+                    Discriminant::Known,
+                ),
+                None => next_discriminant.clone(),
+            };
+            next_discriminant = discriminant.next();
+
+            match crate::attrs::parse_variant_attributes(&variant.attrs) {
+                Ok(variant_attrs) if variant_attrs.other && variant_attrs.range.is_some() => {
+                    errors.push(
+                        syn::Error::new(
+                            ident.span(),
+                            "A variant cannot be both #[bebytes(other)] and #[bebytes(range = ..)]",
+                        )
+                        .to_compile_error(),
+                    );
+                    return None;
+                }
+                Ok(crate::attrs::VariantAttributes {
+                    range: Some((start, end)),
+                    ..
+                }) => {
+                    range_variants.push((ident.clone(), start, end));
+                    return None;
+                }
+                Ok(variant_attrs) if variant_attrs.other => {
+                    if other_variant.is_some() {
+                        errors.push(
+                            syn::Error::new(
+                                ident.span(),
+                                "Only one variant can be marked #[bebytes(other)]",
+                            )
+                            .to_compile_error(),
+                        );
+                    }
+                    other_variant = Some(ident.clone());
+                    return None;
+                }
+                Ok(variant_attrs) => {
+                    if variant_attrs.tag.is_some() {
+                        errors.push(
+                            syn::Error::new(
+                                ident.span(),
+                                "#[bebytes(tag = N)] is only used by data-carrying enums. Use a discriminant (`= N`) for unit variants",
+                            )
+                            .to_compile_error(),
+                        );
+                    }
+                }
+                Err(e) => errors.push(e.to_compile_error()),
+            }
+
             match &variant.fields {
                 syn::Fields::Named(_) => {
                     let error = syn::Error::new(
@@ -113,67 +413,223 @@ pub fn handle_enum(
                 syn::Fields::Unit => {}
             }
 
-            let mut assigned_value = index as u128;
+            Some((ident.clone(), discriminant))
+        })
+        .collect::<Vec<_>>();
 
-            if let Some((_, syn::Expr::Lit(expr_lit))) = &variant.discriminant {
-                if let syn::Lit::Int(token) = &expr_lit.lit {
-                    assigned_value = token.base10_parse::<u128>().unwrap_or_else(|_e| {
-                        let error =
-                            syn::Error::new(token.span(), "Failed to parse discriminant value");
-                        errors.push(error.to_compile_error());
-                        0
-                    });
-                }
-            }
-            (ident.clone(), assigned_value)
+    // Range bounds count towards the wire type like any other discriminant
+    let known_values = discriminants
+        .iter()
+        .filter_map(|(_, discriminant)| match discriminant {
+            Discriminant::Known(value) => Some(*value),
+            Discriminant::Deferred { .. } => None,
         })
+        .chain(
+            range_variants
+                .iter()
+                .flat_map(|(_, start, end)| [*start, *end]),
+        )
         .collect::<Vec<_>>();
+    let min_value = known_values.iter().copied().min().unwrap_or(0);
+    let max_value = known_values.iter().copied().max().unwrap_or(0);
+    // An unsigned repr can hold values above i128::MAX, which evaluate as negative
+    let has_negative = min_value < 0 && !repr.is_some_and(|repr| !repr.is_signed());
+    let values = discriminants
+        .iter()
+        .filter_map(|(ident, discriminant)| match discriminant {
+            Discriminant::Known(value) => Some((ident.clone(), *value as u128)),
+            Discriminant::Deferred { .. } => None,
+        })
+        .collect::<Vec<_>>();
+    let max_discriminant = known_values
+        .iter()
+        .map(|value| *value as u128)
+        .max()
+        .unwrap_or(0);
 
-    let max_discriminant = values.iter().map(|(_, value)| *value).max().unwrap_or(0);
-    let detected_flag_type = FlagType::from_max_value(max_discriminant);
-    let flag_type = explicit_flag_type.unwrap_or(detected_flag_type);
-    let byte_size = flag_type.byte_size();
+    // A value must select exactly one variant
+    for (index, (ident, start, end)) in range_variants.iter().enumerate() {
+        if let Some((previous, _, _)) = range_variants[..index]
+            .iter()
+            .find(|(_, other_start, other_end)| start <= other_end && other_start <= end)
+        {
+            errors.push(
+                syn::Error::new(
+                    ident.span(),
+                    format!("Range of '{ident}' overlaps the range of '{previous}'"),
+                )
+                .to_compile_error(),
+            );
+        }
+        for (unit, discriminant) in &discriminants {
+            if let Discriminant::Known(value) = discriminant {
+                if (start..=end).contains(&value) {
+                    errors.push(
+                        syn::Error::new(
+                            unit.span(),
+                            format!(
+                                "Variant '{unit}' uses discriminant {value}, which is inside the range of '{ident}'"
+                            ),
+                        )
+                        .to_compile_error(),
+                    );
+                }
+            }
+        }
+    }
 
-    #[allow(clippy::cast_possible_truncation)]
-    let typed_values: Vec<_> = values
+    let deferred = discriminants
         .iter()
-        .map(|(ident, val)| {
-            let typed_val = match flag_type {
-                FlagType::U8 => {
-                    let v = *val as u8;
-                    quote! { #v }
-                }
-                FlagType::U16 => {
-                    let v = *val as u16;
-                    quote! { #v }
-                }
-                FlagType::U32 => {
-                    let v = *val as u32;
-                    quote! { #v }
+        .find_map(|(_, discriminant)| match discriminant {
+            Discriminant::Deferred { base, .. } => Some(base),
+            Discriminant::Known(_) => None,
+        });
+    let detected_flag_type = match (deferred, repr) {
+        // Only the compiler knows these values, so the repr type goes on the wire as is
+        (Some(_), Some(repr)) => repr,
+        (Some(base), None) => {
+            errors.push(
+                syn::Error::new_spanned(
+                    base,
+                    "BeBytes cannot evaluate this discriminant. Add #[repr(u8)] (or another fixed-width integer repr) so its wire type is known",
+                )
+                .to_compile_error(),
+            );
+            FlagType::from_max_value(max_discriminant)
+        }
+        (None, _) if has_negative => {
+            repr.unwrap_or_else(|| FlagType::signed_from_range(min_value, max_value))
+        }
+        (None, _) => FlagType::from_max_value(max_discriminant),
+    };
+
+    // Catch-all and range variants hold the raw wire value, so their integer type sets the
+    // enum's width
+    let raw_value_variants = other_variant
+        .iter()
+        .map(|ident| (ident, "#[bebytes(other)]"))
+        .chain(
+            range_variants
+                .iter()
+                .map(|(ident, _, _)| (ident, "#[bebytes(range = ..)]")),
+        );
+    let mut raw_flag_type: Option<FlagType> = None;
+    for (ident, attribute) in raw_value_variants {
+        let variant = variants
+            .iter()
+            .find(|variant| variant.ident == *ident)
+            .expect("raw value variant comes from this enum");
+        let held_type = match &variant.fields {
+            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                match &fields.unnamed[0].ty {
+                    syn::Type::Path(tp) => tp.path.get_ident().and_then(FlagType::from_int_ident),
+                    _ => None,
                 }
-                FlagType::U64 => {
-                    let v = *val as u64;
-                    quote! { #v }
+            }
+            _ => None,
+        };
+        let out_of_range = |held: FlagType| {
+            if has_negative {
+                [min_value, max_value]
+                    .into_iter()
+                    .find(|value| !held.contains(*value))
+                    .map(|value| value.to_string())
+            } else {
+                (max_discriminant > held.max_value()).then(|| max_discriminant.to_string())
+            }
+        };
+        let expected_type = explicit_flag_type.or(raw_flag_type);
+        match held_type {
+            None => errors.push(
+                syn::Error::new_spanned(
+                    &variant.fields,
+                    format!("{attribute} variant must be a tuple variant holding a single fixed-width integer (u8 to u128, i8 to i128) to keep the raw value"),
+                )
+                .to_compile_error(),
+            ),
+            Some(held) if expected_type.is_some_and(|expected| expected != held) => {
+                errors.push(
+                    syn::Error::new_spanned(
+                        &variant.fields,
+                        format!(
+                            "{attribute} variant must hold a {} to match the enum's wire type",
+                            expected_type.map_or("", FlagType::type_name)
+                        ),
+                    )
+                    .to_compile_error(),
+                );
+            }
+            Some(held) if out_of_range(held).is_some() => errors.push(
+                syn::Error::new_spanned(
+                    &variant.fields,
+                    format!(
+                        "{attribute} variant holds a {} which cannot hold discriminant value {}. Use {} or larger.",
+                        held.type_name(),
+                        out_of_range(held).unwrap_or_default(),
+                        detected_flag_type.type_name()
+                    ),
+                )
+                .to_compile_error(),
+            ),
+            Some(held) => raw_flag_type = Some(held),
+        }
+    }
+
+    let flag_type = explicit_flag_type
+        .or(raw_flag_type)
+        .unwrap_or(detected_flag_type);
+    let byte_size = flag_type.byte_size();
+
+    // Known values become literal patterns; deferred ones are compared in match guards
+    let typed_values: Vec<_> = discriminants
+        .iter()
+        .map(|(ident, discriminant)| {
+            let typed_val = match discriminant {
+                Discriminant::Known(value) => {
+                    let literal = flag_type.literal(*value as u128);
+                    quote! { #literal }
                 }
-                FlagType::U128 => {
-                    let v = *val;
-                    quote! { #v }
+                Discriminant::Deferred { base, offset: 0 } => quote! { (#base) },
+                Discriminant::Deferred { base, offset } => {
+                    let offset = proc_macro2::Literal::u128_unsuffixed(*offset);
+                    quote! { (#base) + #offset }
                 }
             };
-            (ident.clone(), typed_val, *val)
+            let is_known = matches!(discriminant, Discriminant::Known(_));
+            (ident.clone(), typed_val, is_known)
         })
         .collect();
 
-    let from_bytes_arms = typed_values
+    let range_patterns = range_variants
         .iter()
-        .map(|(ident, typed_val, _)| {
-            quote! {
-                #typed_val => Ok((Self::#ident, #byte_size)),
+        .map(|(ident, start, end)| {
+            let start = flag_type.literal(*start as u128);
+            let end = flag_type.literal(*end as u128);
+            (ident, quote! { #start..=#end })
+        })
+        .collect::<Vec<_>>();
+
+    let mut from_bytes_arms = typed_values
+        .iter()
+        .map(|(ident, typed_val, is_known)| {
+            if *is_known {
+                quote! {
+                    #typed_val => Ok((Self::#ident, #byte_size)),
+                }
+            } else {
+                quote! {
+                    candidate if candidate == #typed_val => Ok((Self::#ident, #byte_size)),
+                }
             }
         })
         .collect::<Vec<_>>();
+    from_bytes_arms.extend(range_patterns.iter().map(|(ident, pattern)| {
+        quote! {
+            raw @ #pattern => Ok((Self::#ident(raw), #byte_size)),
+        }
+    }));
 
-    let to_bytes_arms = typed_values
+    let mut to_bytes_arms = typed_values
         .iter()
         .map(|(ident, typed_val, _)| {
             quote! {
@@ -181,6 +637,57 @@ pub fn handle_enum(
             }
         })
         .collect::<Vec<_>>();
+    to_bytes_arms.extend(other_variant.iter().map(|ident| {
+        quote! {
+            Self::#ident(value) => *value,
+        }
+    }));
+    // A range variant holding a value outside its range would decode as another variant
+    to_bytes_arms.extend(range_variants.iter().zip(&range_patterns).map(
+        |((ident, start, end), (_, pattern))| {
+            let range = if flag_type.is_signed() {
+                format!("{start}..={end}")
+            } else {
+                format!("{}..={}", *start as u128, *end as u128)
+            };
+            quote! {
+                Self::#ident(value) => {
+                    if !matches!(*value, #pattern) {
+                        panic!(
+                            "Value {} is outside the range {} for variant {}",
+                            value,
+                            #range,
+                            stringify!(#ident)
+                        );
+                    }
+                    *value
+                }
+            }
+        },
+    ));
+
+    // Discriminants only the compiler can evaluate are checked against the ranges there
+    let range_checks = range_variants
+        .iter()
+        .flat_map(|(ident, start, end)| {
+            let start = flag_type.literal(*start as u128);
+            let end = flag_type.literal(*end as u128);
+            typed_values
+                .iter()
+                .filter(|(_, _, is_known)| !is_known)
+                .map(move |(unit, typed_val, _)| {
+                    let message = format!(
+                        "Variant '{unit}' uses a discriminant inside the range of '{ident}'"
+                    );
+                    quote! {
+                        const _: () = assert!(
+                            !(#start <= #typed_val && #typed_val <= #end),
+                            #message
+                        );
+                    }
+                })
+        })
+        .collect::<Vec<_>>();
 
     let min_bits = if max_discriminant == 0 {
         1
@@ -194,14 +701,25 @@ pub fn handle_enum(
         bits
     };
 
-    let try_from_arms = typed_values
+    let mut try_from_arms = typed_values
         .iter()
-        .map(|(ident, typed_val, _)| {
-            quote! {
-                #typed_val => Ok(Self::#ident),
+        .map(|(ident, typed_val, is_known)| {
+            if *is_known {
+                quote! {
+                    #typed_val => Ok(Self::#ident),
+                }
+            } else {
+                quote! {
+                    candidate if candidate == #typed_val => Ok(Self::#ident),
+                }
             }
         })
         .collect::<Vec<_>>();
+    try_from_arms.extend(range_patterns.iter().map(|(ident, pattern)| {
+        quote! {
+            raw @ #pattern => Ok(Self::#ident(raw)),
+        }
+    }));
 
     (
         from_bytes_arms,
@@ -211,5 +729,257 @@ pub fn handle_enum(
         values,
         errors,
         flag_type,
+        other_variant,
+        range_checks,
     )
 }
+
+/// Whether any variant carries fields, making this a tagged union rather than a plain enum
+pub fn is_data_enum(data_enum: &syn::DataEnum) -> bool {
+    data_enum.variants.iter().any(|variant| {
+        !matches!(variant.fields, syn::Fields::Unit) && !is_raw_value_variant(variant)
+    })
+}
+
+/// Per-variant code of a data-carrying enum, assembled into the `BeBytes` impl in lib.rs
+pub struct TaggedEnumParts {
+    pub tag_type: FlagType,
+    /// Expressions evaluating to the maximum body size of each variant in bytes
+    pub body_sizes: Vec<proc_macro2::TokenStream>,
+    /// `Self::Variant { .. } => tag,` arms of `TaggedBody::tag`
+    pub tag_arms: Vec<proc_macro2::TokenStream>,
+    /// Body parsing arms matched against the tag as a `u128`
+    pub be_parsing_arms: Vec<proc_macro2::TokenStream>,
+    pub le_parsing_arms: Vec<proc_macro2::TokenStream>,
+    /// Body writing arms matched against `self`
+    pub be_writing_arms: Vec<proc_macro2::TokenStream>,
+    pub le_writing_arms: Vec<proc_macro2::TokenStream>,
+    pub errors: Vec<proc_macro2::TokenStream>,
+}
+
+struct VariantCode {
+    parsing: Vec<proc_macro2::TokenStream>,
+    writing: Vec<proc_macro2::TokenStream>,
+    accessors: Vec<proc_macro2::TokenStream>,
+    bit_sum: Vec<proc_macro2::TokenStream>,
+}
+
+/// Run the struct field pipeline over a variant's fields. Writing code reads the
+/// fields through the references bound by the variant pattern.
+fn process_variant_fields(
+    fields: &syn::Fields,
+    endianness: crate::consts::Endianness,
+    errors: &mut Vec<proc_macro2::TokenStream>,
+) -> VariantCode {
+    let mut code = VariantCode {
+        parsing: Vec::new(),
+        writing: Vec::new(),
+        accessors: Vec::new(),
+        bit_sum: Vec::new(),
+    };
+    let mut field_limit_check = Vec::new();
+    let mut direct_writing = Vec::new();
+    let mut has_bit_fields = false;
+
+    let mut context = crate::structs::StructContext {
+        field_limit_check: &mut field_limit_check,
+        errors,
+        field_parsing: &mut code.parsing,
+        bit_sum: &mut code.bit_sum,
+        field_writing: &mut code.writing,
+        direct_writing: &mut direct_writing,
+        named_fields: &mut code.accessors,
+        fields,
+        endianness,
+        bit_order: None,
+        c_layout: false,
+        has_bit_fields: &mut has_bit_fields,
+        field_access: crate::structs::FieldAccess::Binding,
+    };
+    crate::structs::handle_struct(&mut context);
+
+    code
+}
+
+/// Assign a tag to every variant: `#[bebytes(tag = N)]`, then an explicit
+/// discriminant, then one more than the previous variant's tag
+fn assign_tags(
+    data_enum: &syn::DataEnum,
+    errors: &mut Vec<proc_macro2::TokenStream>,
+) -> Vec<(syn::Ident, u128)> {
+    let mut tags: Vec<(syn::Ident, u128)> = Vec::new();
+    let mut next_tag = 0u128;
+
+    for variant in &data_enum.variants {
+        let ident = &variant.ident;
+        let variant_attrs = match crate::attrs::parse_variant_attributes(&variant.attrs) {
+            Ok(variant_attrs) => variant_attrs,
+            Err(e) => {
+                errors.push(e.to_compile_error());
+                crate::attrs::VariantAttributes::default()
+            }
+        };
+
+        if variant_attrs.other || variant_attrs.range.is_some() {
+            errors.push(
+                syn::Error::new(
+                    ident.span(),
+                    "#[bebytes(other)] and #[bebytes(range = ..)] are only supported on enums whose other variants are unit variants",
+                )
+                .to_compile_error(),
+            );
+        }
+
+        let tag = if let Some(tag) = variant_attrs.tag {
+            tag
+        } else if let Some((_, discriminant)) = &variant.discriminant {
+            if let Some(value) =
+                evaluate_discriminant(discriminant).and_then(|value| u128::try_from(value).ok())
+            {
+                value
+            } else {
+                errors.push(
+                    syn::Error::new_spanned(
+                        discriminant,
+                        "Unsupported discriminant for a data-carrying enum. Use #[bebytes(tag = N)] with an integer literal",
+                    )
+                    .to_compile_error(),
+                );
+                next_tag
+            }
+        } else {
+            next_tag
+        };
+
+        if let Some((previous, _)) = tags.iter().find(|(_, value)| *value == tag) {
+            errors.push(
+                syn::Error::new(
+                    ident.span(),
+                    format!(
+                        "Variant '{ident}' uses tag {tag}, which is already used by '{previous}'"
+                    ),
+                )
+                .to_compile_error(),
+            );
+        }
+
+        next_tag = tag.saturating_add(1);
+        tags.push((ident.clone(), tag));
+    }
+
+    tags
+}
+
+/// Generate the body parsing and writing arms of a data-carrying enum. The tag is
+/// handled by the caller; variant fields support everything a struct does.
+pub fn handle_tagged_enum(
+    data_enum: &syn::DataEnum,
+    enum_attrs: &crate::attrs::TaggedEnumAttributes,
+) -> TaggedEnumParts {
+    let mut errors = Vec::new();
+    let tags = assign_tags(data_enum, &mut errors);
+
+    let max_tag = tags.iter().map(|(_, tag)| *tag).max().unwrap_or(0);
+    let detected_tag_type = FlagType::from_max_value(max_tag);
+    let tag_type = enum_attrs.tag_type.unwrap_or(detected_tag_type);
+    for (ident, tag) in &tags {
+        if *tag > tag_type.max_value() {
+            errors.push(
+                syn::Error::new(
+                    ident.span(),
+                    format!(
+                        "Tag type {} cannot hold tag value {}. Use {} or larger.",
+                        tag_type.type_name(),
+                        tag,
+                        FlagType::from_max_value(*tag).type_name()
+                    ),
+                )
+                .to_compile_error(),
+            );
+        }
+    }
+
+    let mut parts = TaggedEnumParts {
+        tag_type,
+        body_sizes: Vec::new(),
+        tag_arms: Vec::new(),
+        be_parsing_arms: Vec::new(),
+        le_parsing_arms: Vec::new(),
+        be_writing_arms: Vec::new(),
+        le_writing_arms: Vec::new(),
+        errors: Vec::new(),
+    };
+
+    for (variant, (_, tag)) in data_enum.variants.iter().zip(&tags) {
+        let ident = &variant.ident;
+        let tag_value = FlagType::U128.literal(*tag);
+        let constructor =
+            crate::utils::fields_constructor(&quote! { Self::#ident }, &variant.fields);
+        let wildcard = match &variant.fields {
+            syn::Fields::Named(_) => quote! { Self::#ident { .. } },
+            syn::Fields::Unnamed(_) => quote! { Self::#ident(..) },
+            syn::Fields::Unit => quote! { Self::#ident },
+        };
+        parts.tag_arms.push(quote! { #wildcard => #tag_value, });
+
+        for endianness in [
+            crate::consts::Endianness::Big,
+            crate::consts::Endianness::Little,
+        ] {
+            // Both passes report the same field errors, so keep only the first
+            let mut le_errors = Vec::new();
+            let variant_errors = if endianness == crate::consts::Endianness::Big {
+                &mut errors
+            } else {
+                &mut le_errors
+            };
+            let code = process_variant_fields(&variant.fields, endianness, variant_errors);
+            let VariantCode {
+                parsing,
+                writing,
+                accessors,
+                bit_sum,
+            } = code;
+
+            let parsing_arm = quote! {
+                #tag_value => {
+                    #(#parsing)*
+                    Ok((#constructor, usize::div_ceil(_bit_sum as usize, 8)))
+                }
+            };
+            let writing_arm = quote! {
+                #constructor => {
+                    #(
+                        #accessors
+                        {
+                            let bytes = &mut buf;
+                            #writing
+                        }
+                    )*
+                }
+            };
+
+            if endianness == crate::consts::Endianness::Big {
+                parts.body_sizes.push(if bit_sum.is_empty() {
+                    quote! { 0 }
+                } else {
+                    quote! {
+                        {
+                            let mut bit_sum = 0;
+                            #(#bit_sum)*
+                            bit_sum / 8
+                        }
+                    }
+                });
+                parts.be_parsing_arms.push(parsing_arm);
+                parts.be_writing_arms.push(writing_arm);
+            } else {
+                parts.le_parsing_arms.push(parsing_arm);
+                parts.le_writing_arms.push(writing_arm);
+            }
+        }
+    }
+
+    parts.errors = errors;
+    parts
+}
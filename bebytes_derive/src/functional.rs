@@ -130,6 +130,24 @@ pub struct AttributeData {
     pub is_bits_attribute: bool,
     pub until_marker: Option<u8>,
     pub after_marker: Option<u8>,
+    pub tag_from: Option<Vec<Ident>>,
+    /// `#[bebytes(bitflags(u8))]`: integer type of a `bitflags` field
+    pub bitflags: Option<syn::Type>,
+    pub unknown_bits: Option<crate::enums::UnknownBits>,
+    /// `#[bebytes(byte_length)]`: the vector's size counts bytes instead of elements
+    pub byte_length: bool,
+    /// `#[bebytes(present_if = flags.has_ts)]`: an `Option` is present when this earlier field is set
+    pub present_if: Option<Vec<Ident>>,
+    /// `#[bebytes(none_value = 0xFFFF)]`: an `Option` is `None` when it holds this value
+    pub none_value: Option<syn::Expr>,
+    /// `#[bebytes(tag_type = u16)]`: integer type of an `Option`'s tag
+    pub tag_type: Option<crate::enums::FlagType>,
+    /// `#[bebytes(bytes = 3)]`: number of bytes an integer field occupies on the wire
+    pub byte_width: Option<usize>,
+    /// `#[bebytes(endian = "little")]`: byte order of the field, whichever method is used
+    pub endian: Option<crate::consts::Endianness>,
+    /// `#[bebytes(word_order = "CDAB")]`: order of the 16-bit words of a 32 or 64-bit value
+    pub word_order: Option<crate::word_order::WordOrder>,
 }
 
 impl AttributeData {
@@ -176,6 +194,16 @@ impl AttributeData {
             acc.is_bits_attribute |= attr.is_bits_attribute;
             acc.until_marker = attr.until_marker.or(acc.until_marker);
             acc.after_marker = attr.after_marker.or(acc.after_marker);
+            acc.tag_from = attr.tag_from.or(acc.tag_from);
+            acc.bitflags = attr.bitflags.or(acc.bitflags);
+            acc.unknown_bits = attr.unknown_bits.or(acc.unknown_bits);
+            acc.byte_length |= attr.byte_length;
+            acc.present_if = attr.present_if.or(acc.present_if);
+            acc.none_value = attr.none_value.or(acc.none_value);
+            acc.tag_type = attr.tag_type.or(acc.tag_type);
+            acc.byte_width = attr.byte_width.or(acc.byte_width);
+            acc.endian = attr.endian.or(acc.endian);
+            acc.word_order = attr.word_order.or(acc.word_order);
             acc
         })
     }
@@ -221,11 +249,19 @@ pub mod pure_helpers {
         Clone,
     }
 
-    pub fn create_field_accessor(field_name: &Ident, mode: AccessorMode) -> TokenStream {
+    /// Bind a field's value to a local; `field_access` is a place expression
+    /// such as `self.name` or `*name`
+    pub fn create_field_accessor(
+        field_name: &Ident,
+        field_access: &TokenStream,
+        mode: AccessorMode,
+    ) -> TokenStream {
         match mode {
-            AccessorMode::Copy => quote! { let #field_name = self.#field_name; },
-            AccessorMode::Reference => quote! { let #field_name = &self.#field_name; },
-            AccessorMode::Clone => quote! { let #field_name = self.#field_name.clone(); },
+            AccessorMode::Copy => quote! { let #field_name = #field_access; },
+            AccessorMode::Reference => quote! { let #field_name = &#field_access; },
+            AccessorMode::Clone => {
+                quote! { let #field_name = ::core::clone::Clone::clone(&#field_access); }
+            }
         }
     }
 
@@ -637,6 +673,9 @@ pub mod pure_helpers {
         field_type: &syn::Type,
         size: usize,
     ) -> TokenStream {
+        if let Some(int_type) = signed_bit_field_type(field_type) {
+            return create_signed_bit_field_check(field_name, int_type, size);
+        }
         let mask: u128 = (1 << size) - 1;
 
         // Special handling for char type
@@ -659,6 +698,130 @@ pub mod pure_helpers {
         }
     }
 
+    /// Signed integer type of a bit field, which is range-checked and sign-extended
+    pub fn signed_bit_field_type(field_type: &syn::Type) -> Option<crate::enums::FlagType> {
+        match field_type {
+            syn::Type::Path(tp) => tp
+                .path
+                .get_ident()
+                .and_then(crate::enums::FlagType::from_int_ident)
+                .filter(|int_type| int_type.is_signed()),
+            _ => None,
+        }
+    }
+
+    /// Panics with `InvalidSignedBitField` when a signed bit field is outside the two's complement
+    /// range of `size` bits. Empty when the field uses every bit of its type
+    pub fn create_signed_bit_field_check(
+        field_name: &Ident,
+        int_type: crate::enums::FlagType,
+        size: usize,
+    ) -> TokenStream {
+        signed_bit_range_check(
+            &quote! { #field_name },
+            &quote! { stringify!(#field_name) },
+            int_type,
+            size,
+        )
+    }
+
+    #[allow(clippy::cast_sign_loss)]
+    fn signed_bit_range_check(
+        value: &TokenStream,
+        label: &TokenStream,
+        int_type: crate::enums::FlagType,
+        size: usize,
+    ) -> TokenStream {
+        if size >= 8 * int_type.byte_size() {
+            return quote! {};
+        }
+        // Negative bounds are passed as their two's complement bit pattern
+        let min = int_type.literal(-(1i128 << (size - 1)) as u128);
+        let max = int_type.literal((1u128 << (size - 1)) - 1);
+        quote! {
+            if !(#min..=#max).contains(&#value) {
+                panic!(
+                    "{}",
+                    ::bebytes::BeBytesError::InvalidSignedBitField {
+                        value: #value as i128,
+                        max: #max as i128,
+                        field: #label,
+                    }
+                );
+            }
+        }
+    }
+
+    /// Panics with `InvalidBitField` or `InvalidSignedBitField` when an element of a
+    /// `#[bits(N)]` array doesn't fit in
+    /// its bits. `label` names the element, e.g. `nibbles[3]`
+    pub fn create_bit_array_element_check(
+        value: &TokenStream,
+        element_type: &syn::Type,
+        label: &str,
+        size: usize,
+    ) -> TokenStream {
+        if let Some(int_type) = signed_bit_field_type(element_type) {
+            return signed_bit_range_check(value, &quote! { #label }, int_type, size);
+        }
+        let full_width = crate::utils::get_primitive_type_max_bits(element_type)
+            .map_or(true, |max_bits| size >= max_bits);
+        if full_width {
+            return quote! {};
+        }
+        let mask: u128 = (1 << size) - 1;
+        let value = match element_type {
+            syn::Type::Path(tp) if tp.path.is_ident("char") => quote! { (#value as u32) },
+            _ => value.clone(),
+        };
+        quote! {
+            if #value as u128 > #mask {
+                panic!(
+                    "{}",
+                    ::bebytes::BeBytesError::InvalidBitField {
+                        value: #value as u128,
+                        max: #mask,
+                        field: #label,
+                    }
+                );
+            }
+        }
+    }
+
+    /// Panics when `value` doesn't fit in the `width` bytes of a `#[bebytes(bytes = N)]`
+    /// integer. Empty when the width covers the whole type
+    #[allow(clippy::cast_sign_loss)]
+    pub fn create_byte_width_check(
+        value: &TokenStream,
+        field_label: &str,
+        int_type: crate::enums::FlagType,
+        width: usize,
+    ) -> TokenStream {
+        if width >= int_type.byte_size() {
+            return quote! {};
+        }
+        let bits = 8 * width;
+        let out_of_range = if int_type.is_signed() {
+            // Negative bounds are passed as their two's complement bit pattern
+            let min = int_type.literal(-(1i128 << (bits - 1)) as u128);
+            let max = int_type.literal((1u128 << (bits - 1)) - 1);
+            quote! { !(#min..=#max).contains(&#value) }
+        } else {
+            let max = int_type.literal((1u128 << bits) - 1);
+            quote! { #value > #max }
+        };
+        quote! {
+            if #out_of_range {
+                panic!(
+                    "Value {} for field {} does not fit in {} bytes.",
+                    #value,
+                    #field_label,
+                    #width
+                );
+            }
+        }
+    }
+
     /// Generate field access path from a vector of idents
     pub fn generate_field_access_path(ident_path: &[Ident]) -> TokenStream {
         if ident_path.len() == 1 {
@@ -1208,16 +1371,37 @@ pub mod functional_attrs {
             ));
         }
 
-        // Check for multiple endian attributes
+        conflict_errors.extend(endian_conflicts(attributes));
+
+        // If there are conflict errors, return them
+        if !conflict_errors.is_empty() {
+            return Err(conflict_errors);
+        }
+
+        error_utils::aggregate_results(flattened.into_iter()).map(AttributeData::merge)
+    }
+
+    /// Errors for a field given more than one byte order, or a byte order on a bit field
+    fn endian_conflicts(attributes: &[syn::Attribute]) -> Vec<syn::Error> {
         let endian_attrs: Vec<_> = attributes
             .iter()
             .filter(|attr| {
                 attr.path().is_ident("bebytes") && {
                     let mut is_endian = false;
                     if let Ok(()) = attr.parse_nested_meta(|meta| {
-                        if meta.path.is_ident("big_endian") || meta.path.is_ident("little_endian") {
+                        if meta.path.is_ident("big_endian")
+                            || meta.path.is_ident("little_endian")
+                            || meta.path.is_ident("endian")
+                        {
                             is_endian = true;
                         }
+                        // Skip the values of this and other options to reach the rest
+                        if meta.input.peek(syn::Token![=]) {
+                            let _: syn::Expr = meta.value()?.parse()?;
+                        } else if meta.input.peek(syn::token::Paren) {
+                            let _content;
+                            syn::parenthesized!(_content in meta.input);
+                        }
                         Ok(())
                     }) {}
                     is_endian
@@ -1225,19 +1409,23 @@ pub mod functional_attrs {
             })
             .collect();
 
+        let mut errors = Vec::new();
         if endian_attrs.len() > 1 {
-            conflict_errors.push(syn::Error::new_spanned(
-                endian_attrs.first().unwrap(),
+            errors.push(syn::Error::new_spanned(
+                endian_attrs[0],
                 "Cannot specify both big_endian and little_endian attributes on the same field",
             ));
         }
-
-        // If there are conflict errors, return them
-        if !conflict_errors.is_empty() {
-            return Err(conflict_errors);
+        if let (Some(endian_attr), true) = (
+            endian_attrs.first(),
+            attributes.iter().any(|attr| attr.path().is_ident("bits")),
+        ) {
+            errors.push(syn::Error::new_spanned(
+                endian_attr,
+                "Bit fields follow the struct's bit order. Use #[bebytes(bit_order = ..)] on the struct instead",
+            ));
         }
-
-        error_utils::aggregate_results(flattened.into_iter()).map(AttributeData::merge)
+        errors
     }
 
     /// Parse bits attribute functionally
@@ -1258,6 +1446,11 @@ pub mod functional_attrs {
                     return Ok(None);
                 }
 
+                // #[bits(12..16)] places the field, its size is the width of the range
+                if let Some(range) = crate::bit_validation::parse_bit_range(&list.tokens)? {
+                    return Ok(Some(range.len()));
+                }
+
                 // Try to parse as integer literal
                 let parser =
                     syn::punctuated::Punctuated::<LitInt, syn::Token![,]>::parse_terminated;
@@ -1379,14 +1572,7 @@ pub mod functional_attrs {
             syn::Meta::List(list) => {
                 // Parse tokens inside FromField(...)
                 let tokens = list.tokens.clone();
-                let parser = syn::punctuated::Punctuated::<syn::Ident, syn::Token![.]>::parse_separated_nonempty;
-                match parser.parse2(tokens) {
-                    Ok(punctuated) => {
-                        // Convert punctuated list to Vec<Ident>
-                        field_path = punctuated.into_iter().collect();
-                    }
-                    Err(e) => return Err(e),
-                }
+                field_path = parse_field_path.parse2(tokens)?;
             }
             _ => {
                 return Err(syn::Error::new_spanned(
@@ -1403,6 +1589,31 @@ pub mod functional_attrs {
         }
     }
 
+    /// Parse a dotted field path such as `count` or `header.payload_size`.
+    /// A leading integer refers to a positional field of a tuple struct (`0` binds to `field_0`)
+    pub fn parse_field_path(input: syn::parse::ParseStream) -> syn::Result<Vec<syn::Ident>> {
+        let mut field_path = Vec::new();
+        if input.peek(LitInt) {
+            let index: LitInt = input.parse()?;
+            field_path.push(quote::format_ident!(
+                "field_{}",
+                index.base10_parse::<usize>()?,
+                span = index.span()
+            ));
+        } else {
+            field_path.push(input.parse::<syn::Ident>()?);
+        }
+        while input.peek(syn::Token![.]) {
+            input.parse::<syn::Token![.]>()?;
+            field_path.push(input.parse::<syn::Ident>()?);
+        }
+        // Inside `#[bebytes(..)]` the path may be followed by other attributes
+        if !input.is_empty() && !input.peek(syn::Token![,]) {
+            return Err(input.error("Expected a field path such as `count` or `header.count`"));
+        }
+        Ok(field_path)
+    }
+
     /// Parse `UntilMarker` attribute functionally
     /// Handles `#[UntilMarker(0xFF)]`, `#[UntilMarker(255)]`, or `#[UntilMarker('\n')]`
     pub fn parse_until_marker_attribute_functional(
@@ -1476,7 +1687,8 @@ pub mod functional_attrs {
     }
 
     /// Parse bebytes attribute functionally
-    /// Handles #[bebytes(size = "expression")] and similar bebytes-specific attributes
+    /// Handles `#[bebytes(size = "expression")]`, `#[bebytes(tag_from = field.path)]` and similar
+    /// bebytes-specific attributes
     pub fn parse_bebytes_attribute_functional(
         attr: &syn::Attribute,
     ) -> Result<Option<AttributeData>, syn::Error> {
@@ -1502,9 +1714,80 @@ pub mod functional_attrs {
                 result.size_expression = Some(parsed_expr);
                 found_something = true;
                 Ok(())
+            } else if meta.path.is_ident("tag_from") {
+                result.tag_from = Some(parse_field_path(meta.value()?)?);
+                found_something = true;
+                Ok(())
+            } else if meta.path.is_ident("bitflags") {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                let bits_type: syn::Ident = content.parse()?;
+                if crate::enums::FlagType::from_int_ident(&bits_type).is_none() {
+                    return Err(syn::Error::new(
+                        bits_type.span(),
+                        "Expected the integer type of the flags, e.g. bitflags(u8)",
+                    ));
+                }
+                result.bitflags = Some(syn::parse_quote!(#bits_type));
+                found_something = true;
+                Ok(())
+            } else if meta.path.is_ident("byte_length") {
+                result.byte_length = true;
+                found_something = true;
+                Ok(())
+            } else if meta.path.is_ident("present_if") {
+                result.present_if = Some(parse_field_path(meta.value()?)?);
+                found_something = true;
+                Ok(())
+            } else if meta.path.is_ident("none_value") {
+                result.none_value = Some(meta.value()?.parse()?);
+                found_something = true;
+                Ok(())
+            } else if meta.path.is_ident("tag_type") {
+                let type_ident: syn::Ident = meta.value()?.parse()?;
+                result.tag_type = Some(
+                    crate::enums::FlagType::from_ident(&type_ident).ok_or_else(|| {
+                        syn::Error::new(
+                            type_ident.span(),
+                            "Invalid tag type. Expected u8, u16, u32, u64, or u128",
+                        )
+                    })?,
+                );
+                found_something = true;
+                Ok(())
+            } else if meta.path.is_ident("bytes") {
+                let width: syn::LitInt = meta.value()?.parse()?;
+                let width: usize = width.base10_parse()?;
+                if width == 0 {
+                    return Err(meta.error("bytes must be at least 1"));
+                }
+                result.byte_width = Some(width);
+                found_something = true;
+                Ok(())
+            } else if meta.path.is_ident("unknown_bits") {
+                let policy: syn::LitStr = meta.value()?.parse()?;
+                result.unknown_bits = Some(crate::enums::UnknownBits::from_lit(&policy)?);
+                found_something = true;
+                Ok(())
+            } else if meta.path.is_ident("endian") {
+                result.endian = Some(crate::attrs::parse_endian(&meta.value()?.parse()?)?);
+                found_something = true;
+                Ok(())
+            } else if meta.path.is_ident("big_endian") {
+                result.endian = Some(crate::consts::Endianness::Big);
+                found_something = true;
+                Ok(())
+            } else if meta.path.is_ident("little_endian") {
+                result.endian = Some(crate::consts::Endianness::Little);
+                found_something = true;
+                Ok(())
+            } else if meta.path.is_ident("word_order") {
+                result.word_order = Some(crate::word_order::WordOrder::from_lit(
+                    &meta.value()?.parse()?,
+                )?);
+                found_something = true;
+                Ok(())
             } else {
-                // For other bebytes attributes like big_endian, little_endian, etc., we don't need to handle them here
-                // They are handled elsewhere in the codebase
                 Ok(())
             }
         })?;
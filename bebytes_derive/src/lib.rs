@@ -8,6 +8,7 @@ extern crate alloc;
 
 mod attrs;
 mod bit_validation;
+mod bits_struct;
 mod consts;
 mod enums;
 mod functional;
@@ -16,10 +17,11 @@ mod raw_pointer;
 mod size_expr;
 mod structs;
 mod utils;
+mod word_order;
 
 use proc_macro::TokenStream;
 use quote::{__private::Span, quote};
-use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Fields};
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
 
 #[cfg(feature = "std")]
 use std::vec::Vec;
@@ -30,10 +32,10 @@ use alloc::vec::Vec;
 use consts::Endianness;
 
 /// Calculate the total size of a struct based on its fields
-fn calculate_struct_size(fields: &syn::FieldsNamed) -> Option<usize> {
+fn calculate_struct_size(fields: &syn::Fields) -> Option<usize> {
     let mut total_size = 0usize;
 
-    for field in &fields.named {
+    for field in fields {
         let field_type = &field.ty;
 
         // Check if this is a bit field (has #[bits(N)] attribute)
@@ -42,34 +44,9 @@ fn calculate_struct_size(fields: &syn::FieldsNamed) -> Option<usize> {
             return None;
         }
 
-        // Try to get size of primitive types and arrays
-        if let Ok(size) = crate::utils::get_primitive_type_size(field_type) {
-            total_size += size;
-        } else if let syn::Type::Array(array_type) = field_type {
-            if let syn::Type::Path(element_type) = &*array_type.elem {
-                if element_type.path.is_ident("u8") {
-                    if let syn::Expr::Lit(syn::ExprLit {
-                        lit: syn::Lit::Int(len),
-                        ..
-                    }) = &array_type.len
-                    {
-                        if let Ok(array_len) = len.base10_parse::<usize>() {
-                            total_size += array_len;
-                        } else {
-                            return None;
-                        }
-                    } else {
-                        return None;
-                    }
-                } else {
-                    return None;
-                }
-            } else {
-                return None;
-            }
-        } else {
-            return None;
-        }
+        // Primitive types and arrays of them have a size known here
+        total_size += attrs::parse_byte_width(&field.attrs)
+            .or_else(|| crate::utils::get_fixed_type_size(field_type))?;
     }
 
     if total_size > 0 && total_size <= 256 {
@@ -92,7 +69,7 @@ fn generate_raw_pointer_not_supported() -> proc_macro2::TokenStream {
 /// Generate raw pointer methods for ultra-high-performance encoding
 /// These methods bypass all abstractions and write directly to memory
 fn generate_raw_pointer_methods(
-    fields: &syn::FieldsNamed,
+    fields: &syn::Fields,
     has_bit_fields: bool,
 ) -> proc_macro2::TokenStream {
     // Only generate for structs without bit fields
@@ -162,7 +139,7 @@ fn generate_raw_pointer_methods(
         /// Encode directly to a mutable buffer using raw pointer operations (big-endian)
         /// This method is unsafe and requires the buffer to have sufficient capacity
         #[inline(always)]
-        pub unsafe fn encode_be_to_raw_mut<B: ::bebytes::BufMut>(&self, buf: &mut B) -> ::core::result::Result<(), ::bebytes::BeBytesError> {
+        pub unsafe fn encode_be_to_raw_mut<__B: ::bebytes::BufMut>(&self, buf: &mut __B) -> ::core::result::Result<(), ::bebytes::BeBytesError> {
             let required_capacity = Self::field_size();
             if buf.remaining_mut() < required_capacity {
                 return Err(::bebytes::BeBytesError::InsufficientData {
@@ -181,7 +158,7 @@ fn generate_raw_pointer_methods(
         /// Encode directly to a mutable buffer using raw pointer operations (little-endian)
         /// This method is unsafe and requires the buffer to have sufficient capacity
         #[inline(always)]
-        pub unsafe fn encode_le_to_raw_mut<B: ::bebytes::BufMut>(&self, buf: &mut B) -> ::core::result::Result<(), ::bebytes::BeBytesError> {
+        pub unsafe fn encode_le_to_raw_mut<__B: ::bebytes::BufMut>(&self, buf: &mut __B) -> ::core::result::Result<(), ::bebytes::BeBytesError> {
             let required_capacity = Self::field_size();
             if buf.remaining_mut() < required_capacity {
                 return Err(::bebytes::BeBytesError::InsufficientData {
@@ -202,7 +179,7 @@ fn generate_raw_pointer_methods(
 /// Generate optimized direct writing methods for structs with bit fields
 /// Uses stack-allocated arrays when possible to reduce allocation overhead
 fn generate_bit_field_optimized_methods(
-    _struct_field_names: &[&Option<syn::Ident>],
+    _struct_field_names: &[syn::Ident],
     _named_fields: &[proc_macro2::TokenStream],
     _le_named_fields: &[proc_macro2::TokenStream],
     _be_field_writing: &[proc_macro2::TokenStream],
@@ -210,7 +187,7 @@ fn generate_bit_field_optimized_methods(
 ) -> proc_macro2::TokenStream {
     quote! {
         #[inline]
-        fn encode_be_to<B: ::bebytes::BufMut>(&self, buf: &mut B) -> ::core::result::Result<(), ::bebytes::BeBytesError> {
+        fn encode_be_to<__B: ::bebytes::BufMut>(&self, buf: &mut __B) -> ::core::result::Result<(), ::bebytes::BeBytesError> {
             let required_capacity = Self::field_size();
             if buf.remaining_mut() < required_capacity {
                 return Err(::bebytes::BeBytesError::InsufficientData {
@@ -228,7 +205,7 @@ fn generate_bit_field_optimized_methods(
         }
 
         #[inline]
-        fn encode_le_to<B: ::bebytes::BufMut>(&self, buf: &mut B) -> ::core::result::Result<(), ::bebytes::BeBytesError> {
+        fn encode_le_to<__B: ::bebytes::BufMut>(&self, buf: &mut __B) -> ::core::result::Result<(), ::bebytes::BeBytesError> {
             let required_capacity = Self::field_size();
             if buf.remaining_mut() < required_capacity {
                 return Err(::bebytes::BeBytesError::InsufficientData {
@@ -247,6 +224,677 @@ fn generate_bit_field_optimized_methods(
     }
 }
 
+/// Generate `to_bytes` and `try_from_bytes` for a struct with a default byte order
+/// given by `#[bebytes(endian = ..)]`
+fn generate_default_endian_methods(endian: Option<Endianness>) -> proc_macro2::TokenStream {
+    let Some(endian) = endian else {
+        return quote! {};
+    };
+    let to_bytes_method = utils::get_to_bytes_method(endian);
+    let try_from_bytes_method = utils::get_try_from_bytes_method(endian);
+    let order = match endian {
+        Endianness::Big => "big-endian",
+        Endianness::Little => "little-endian",
+    };
+    let to_bytes_doc = format!("Convert to bytes in the struct's default {order} byte order");
+    let try_from_bytes_doc = format!("Parse from bytes in the struct's default {order} byte order");
+    quote! {
+        #[doc = #to_bytes_doc]
+        #[inline]
+        pub fn to_bytes(&self) -> ::bebytes::Vec<u8> {
+            <Self as ::bebytes::BeBytes>::#to_bytes_method(self)
+        }
+
+        #[doc = #try_from_bytes_doc]
+        #[inline]
+        pub fn try_from_bytes(
+            bytes: &[u8],
+        ) -> ::core::result::Result<(Self, usize), ::bebytes::BeBytesError> {
+            <Self as ::bebytes::BeBytes>::#try_from_bytes_method(bytes)
+        }
+    }
+}
+
+/// Generate `From` conversions between a `#[bebytes(transparent)]` newtype and its inner type
+fn generate_transparent_conversions(
+    name: &syn::Ident,
+    generics: &syn::Generics,
+    fields: &syn::Fields,
+) -> proc_macro2::TokenStream {
+    let Some(field) = fields.iter().next() else {
+        return quote! {};
+    };
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let inner_type = &field.ty;
+    let member = utils::field_member(0, field);
+    let wrap = if let Some(ident) = &field.ident {
+        quote! { Self { #ident: inner } }
+    } else {
+        quote! { Self(inner) }
+    };
+
+    // `impl<T> From<Wrapper<T>> for T` is rejected by the orphan rules, so the
+    // unwrapping conversion is only generated when the inner type is concrete
+    let is_bare_type_param = matches!(
+        inner_type,
+        syn::Type::Path(tp) if tp.qself.is_none()
+            && generics.type_params().any(|param| tp.path.is_ident(&param.ident))
+    );
+    let unwrap = if is_bare_type_param {
+        quote! {}
+    } else {
+        quote! {
+            impl #impl_generics ::core::convert::From<#name #ty_generics> for #inner_type #where_clause {
+                #[inline]
+                fn from(outer: #name #ty_generics) -> Self {
+                    outer.#member
+                }
+            }
+        }
+    };
+
+    quote! {
+        impl #impl_generics ::core::convert::From<#inner_type> for #name #ty_generics #where_clause {
+            #[inline]
+            fn from(inner: #inner_type) -> Self {
+                #wrap
+            }
+        }
+
+        #unwrap
+    }
+}
+
+/// Generate the `TaggedBody` and `BeBytes` impls of a data-carrying enum: a tag followed
+/// by the variant's fields
+#[allow(clippy::too_many_lines)]
+fn generate_tagged_enum(
+    name: &syn::Ident,
+    generics: &syn::Generics,
+    attributes: &[syn::Attribute],
+    data_enum: &syn::DataEnum,
+) -> proc_macro2::TokenStream {
+    let enum_attrs = match attrs::parse_tagged_enum_attributes(attributes) {
+        Ok(enum_attrs) => enum_attrs,
+        Err(e) => return e.to_compile_error(),
+    };
+    let enums::TaggedEnumParts {
+        tag_type,
+        body_sizes,
+        tag_arms,
+        be_parsing_arms,
+        le_parsing_arms,
+        be_writing_arms,
+        le_writing_arms,
+        errors,
+    } = enums::handle_tagged_enum(data_enum, &enum_attrs);
+
+    if !errors.is_empty() {
+        return quote! {
+            #(#errors)*
+        };
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let tag_size = tag_type.byte_size();
+    let tag_tokens = tag_type.type_tokens();
+    let be_tag_endianness = enum_attrs.tag_endian.unwrap_or(Endianness::Big);
+    let le_tag_endianness = enum_attrs.tag_endian.unwrap_or(Endianness::Little);
+    let be_tag_read = tag_type.read_tokens(be_tag_endianness);
+    let le_tag_read = tag_type.read_tokens(le_tag_endianness);
+    let be_tag_to_bytes = utils::get_to_bytes_method(be_tag_endianness);
+    let le_tag_to_bytes = utils::get_to_bytes_method(le_tag_endianness);
+
+    let unknown_tag = if tag_type == enums::FlagType::U8 {
+        quote! {
+            match u8::try_from(tag) {
+                Ok(value) => Err(::bebytes::BeBytesError::InvalidDiscriminant {
+                    value,
+                    type_name: stringify!(#name),
+                }),
+                Err(_) => Err(::bebytes::BeBytesError::InvalidDiscriminantLarge {
+                    value: tag,
+                    type_name: stringify!(#name),
+                }),
+            }
+        }
+    } else {
+        quote! {
+            Err(::bebytes::BeBytesError::InvalidDiscriminantLarge {
+                value: tag,
+                type_name: stringify!(#name),
+            })
+        }
+    };
+
+    let body_prelude = quote! {
+        let mut _bit_sum = 0;
+        let mut byte_index = 0;
+        let mut end_byte_index = 0;
+        let buffer_size = bytes.len();
+    };
+
+    let tag_prelude = quote! {
+        if bytes.is_empty() {
+            return Err(::bebytes::BeBytesError::EmptyBuffer);
+        }
+        if bytes.len() < #tag_size {
+            return Err(::bebytes::BeBytesError::InsufficientData {
+                expected: #tag_size,
+                actual: bytes.len(),
+            });
+        }
+    };
+
+    quote! {
+        impl #impl_generics ::bebytes::TaggedBody for #name #ty_generics #where_clause {
+            #[inline]
+            fn tag(&self) -> u128 {
+                match self {
+                    #(#tag_arms)*
+                }
+            }
+
+            #[inline]
+            fn try_from_be_body(tag: u128, bytes: &[u8]) -> ::core::result::Result<(Self, usize), ::bebytes::BeBytesError> {
+                #body_prelude
+                match tag {
+                    #(#be_parsing_arms)*
+                    _ => #unknown_tag,
+                }
+            }
+
+            #[inline]
+            fn try_from_le_body(tag: u128, bytes: &[u8]) -> ::core::result::Result<(Self, usize), ::bebytes::BeBytesError> {
+                #body_prelude
+                match tag {
+                    #(#le_parsing_arms)*
+                    _ => #unknown_tag,
+                }
+            }
+
+            #[inline]
+            fn to_be_body(&self) -> ::bebytes::Vec<u8> {
+                let mut buf = ::bebytes::BytesMut::with_capacity(<Self as ::bebytes::BeBytes>::field_size());
+                let mut _bit_sum = 0;
+                match self {
+                    #(#be_writing_arms)*
+                }
+                buf.freeze().to_vec()
+            }
+
+            #[inline]
+            fn to_le_body(&self) -> ::bebytes::Vec<u8> {
+                let mut buf = ::bebytes::BytesMut::with_capacity(<Self as ::bebytes::BeBytes>::field_size());
+                let mut _bit_sum = 0;
+                match self {
+                    #(#le_writing_arms)*
+                }
+                buf.freeze().to_vec()
+            }
+        }
+
+        impl #impl_generics ::bebytes::BeBytes for #name #ty_generics #where_clause {
+            /// Size of the tag plus the largest variant body
+            #[inline]
+            fn field_size() -> usize {
+                let mut max_body = 0;
+                #(
+                    max_body = usize::max(max_body, #body_sizes);
+                )*
+                #tag_size + max_body
+            }
+
+            #[inline]
+            fn try_from_be_bytes(bytes: &[u8]) -> ::core::result::Result<(Self, usize), ::bebytes::BeBytesError> {
+                #tag_prelude
+                let tag = #be_tag_read;
+                let (value, consumed) =
+                    <Self as ::bebytes::TaggedBody>::try_from_be_body(u128::from(tag), &bytes[#tag_size..])?;
+                Ok((value, #tag_size + consumed))
+            }
+
+            #[inline]
+            fn to_be_bytes(&self) -> ::bebytes::Vec<u8> {
+                let body = <Self as ::bebytes::TaggedBody>::to_be_body(self);
+                let tag = <Self as ::bebytes::TaggedBody>::tag(self) as #tag_tokens;
+                let mut bytes = ::bebytes::Vec::with_capacity(#tag_size + body.len());
+                bytes.extend_from_slice(&tag.#be_tag_to_bytes());
+                bytes.extend_from_slice(&body);
+                bytes
+            }
+
+            #[inline]
+            fn to_be_bytes_buf(&self) -> ::bebytes::Bytes {
+                ::bebytes::Bytes::from(self.to_be_bytes())
+            }
+
+            #[inline]
+            fn try_from_le_bytes(bytes: &[u8]) -> ::core::result::Result<(Self, usize), ::bebytes::BeBytesError> {
+                #tag_prelude
+                let tag = #le_tag_read;
+                let (value, consumed) =
+                    <Self as ::bebytes::TaggedBody>::try_from_le_body(u128::from(tag), &bytes[#tag_size..])?;
+                Ok((value, #tag_size + consumed))
+            }
+
+            #[inline]
+            fn to_le_bytes(&self) -> ::bebytes::Vec<u8> {
+                let body = <Self as ::bebytes::TaggedBody>::to_le_body(self);
+                let tag = <Self as ::bebytes::TaggedBody>::tag(self) as #tag_tokens;
+                let mut bytes = ::bebytes::Vec::with_capacity(#tag_size + body.len());
+                bytes.extend_from_slice(&tag.#le_tag_to_bytes());
+                bytes.extend_from_slice(&body);
+                bytes
+            }
+
+            #[inline]
+            fn to_le_bytes_buf(&self) -> ::bebytes::Bytes {
+                ::bebytes::Bytes::from(self.to_le_bytes())
+            }
+
+            #[inline]
+            fn encode_be_to<__B: ::bebytes::BufMut>(&self, buf: &mut __B) -> ::core::result::Result<(), ::bebytes::BeBytesError> {
+                // The encoded size depends on the variant, so encode first and then check capacity
+                let encoded = self.to_be_bytes();
+                if buf.remaining_mut() < encoded.len() {
+                    return Err(::bebytes::BeBytesError::InsufficientData {
+                        expected: encoded.len(),
+                        actual: buf.remaining_mut(),
+                    });
+                }
+                buf.put_slice(&encoded);
+                Ok(())
+            }
+
+            #[inline]
+            fn encode_le_to<__B: ::bebytes::BufMut>(&self, buf: &mut __B) -> ::core::result::Result<(), ::bebytes::BeBytesError> {
+                let encoded = self.to_le_bytes();
+                if buf.remaining_mut() < encoded.len() {
+                    return Err(::bebytes::BeBytesError::InsufficientData {
+                        expected: encoded.len(),
+                        actual: buf.remaining_mut(),
+                    });
+                }
+                buf.put_slice(&encoded);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Introspection helpers a unit enum asks for with `#[bebytes(introspect)]`.
+/// `#[bebytes(no_from_str)]` and `#[bebytes(no_display)]` leave those traits to the user
+#[derive(Clone, Copy)]
+struct EnumIntrospection {
+    enabled: bool,
+    from_str: bool,
+    display: bool,
+}
+
+/// Generate the introspection helpers of a unit enum: `ALL`, `name()`, `FromStr` and
+/// `Display`. Catch-all and range variants have a name but can't be listed or parsed.
+fn generate_enum_introspection(
+    name: &syn::Ident,
+    data_enum: &syn::DataEnum,
+    introspection: EnumIntrospection,
+) -> proc_macro2::TokenStream {
+    if !introspection.enabled {
+        return quote! {};
+    }
+    let unit_variants = data_enum
+        .variants
+        .iter()
+        .filter(|variant| matches!(variant.fields, Fields::Unit))
+        .map(|variant| &variant.ident)
+        .collect::<Vec<_>>();
+    let unit_names = unit_variants
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    let name_arms = data_enum.variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let variant_name = ident.to_string();
+        if let Fields::Unit = variant.fields {
+            quote! { #name::#ident => #variant_name, }
+        } else {
+            quote! { #name::#ident(..) => #variant_name, }
+        }
+    });
+
+    // A variant named `Err` makes `Self::Err` ambiguous inside `FromStr`, so spell out the types
+    let from_str = introspection.from_str.then(|| {
+        quote! {
+            impl ::core::str::FromStr for #name {
+                type Err = ::bebytes::BeBytesError;
+
+                fn from_str(s: &str) -> ::core::result::Result<Self, ::bebytes::BeBytesError> {
+                    match s {
+                        #(#unit_names => Ok(#name::#unit_variants),)*
+                        _ => Err(::bebytes::BeBytesError::InvalidVariantName {
+                            type_name: stringify!(#name),
+                        }),
+                    }
+                }
+            }
+        }
+    });
+    let display = introspection.display.then(|| {
+        quote! {
+            impl ::core::fmt::Display for #name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.write_str(self.name())
+                }
+            }
+        }
+    });
+
+    quote! {
+        impl #name {
+            /// Every unit variant, in declaration order
+            pub const ALL: &'static [Self] = &[#(#name::#unit_variants),*];
+
+            /// The name of the variant as written in the enum
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    #(#name_arms)*
+                }
+            }
+        }
+
+        #from_str
+        #display
+    }
+}
+
+/// Generate the `<Name>Set` companion type of a `#[bebytes(flags)]` enum: a typed
+/// combination of flags that can be used as a struct field
+#[allow(clippy::too_many_lines)]
+fn generate_flag_set(
+    name: &syn::Ident,
+    vis: &syn::Visibility,
+    flag_type: enums::FlagType,
+    flags: &[(syn::Ident, u128)],
+    unknown_bits: enums::UnknownBits,
+) -> proc_macro2::TokenStream {
+    let set_name = quote::format_ident!("{}Set", name);
+    let type_tokens = flag_type.type_tokens();
+    let byte_size = flag_type.byte_size();
+    let set_flags = flags
+        .iter()
+        .filter(|(_, value)| *value != 0)
+        .collect::<Vec<_>>();
+    let flag_idents = set_flags.iter().map(|(ident, _)| ident).collect::<Vec<_>>();
+    let flag_names = flag_idents
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    let flag_values = set_flags
+        .iter()
+        .map(|(_, value)| flag_type.literal(*value))
+        .collect::<Vec<_>>();
+    let flag_count = set_flags.len();
+    let all_bits = flag_type.literal(set_flags.iter().fold(0, |all, (_, value)| all | value));
+
+    let unknown_bits_error = if flag_type == enums::FlagType::U8 {
+        quote! {
+            ::bebytes::BeBytesError::InvalidDiscriminant {
+                value,
+                type_name: stringify!(#set_name),
+            }
+        }
+    } else {
+        quote! {
+            ::bebytes::BeBytesError::InvalidDiscriminantLarge {
+                value: value as u128,
+                type_name: stringify!(#set_name),
+            }
+        }
+    };
+    let decode = match unknown_bits {
+        enums::UnknownBits::Reject => quote! {
+            Self::from_bits(value).ok_or(#unknown_bits_error)?
+        },
+        enums::UnknownBits::Keep => quote! { Self::from_bits_retain(value) },
+        enums::UnknownBits::Mask => quote! { Self::from_bits_truncate(value) },
+    };
+    let be_read = flag_type.read_tokens(Endianness::Big);
+    let le_read = flag_type.read_tokens(Endianness::Little);
+    let be_vec_write = flag_type.put_tokens(Endianness::Big, &quote! { &mut buf });
+    let le_vec_write = flag_type.put_tokens(Endianness::Little, &quote! { &mut buf });
+    let be_write = flag_type.put_tokens(Endianness::Big, &quote! { buf });
+    let le_write = flag_type.put_tokens(Endianness::Little, &quote! { buf });
+
+    quote! {
+        #[doc = concat!("A combination of [`", stringify!(#name), "`] flags")]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+        #vis struct #set_name {
+            bits: #type_tokens,
+        }
+
+        impl #set_name {
+            /// The set with no flags
+            pub const fn empty() -> Self {
+                Self { bits: 0 }
+            }
+
+            /// The set with every flag
+            pub const fn all() -> Self {
+                Self { bits: #all_bits }
+            }
+
+            /// Raw bits of the set, including any unknown bits that were kept
+            pub const fn bits(&self) -> #type_tokens {
+                self.bits
+            }
+
+            /// The set with exactly `bits`, or `None` if a bit has no flag
+            pub const fn from_bits(bits: #type_tokens) -> ::core::option::Option<Self> {
+                if bits & !#all_bits == 0 {
+                    ::core::option::Option::Some(Self { bits })
+                } else {
+                    ::core::option::Option::None
+                }
+            }
+
+            /// The set with `bits`, clearing bits that have no flag
+            pub const fn from_bits_truncate(bits: #type_tokens) -> Self {
+                Self { bits: bits & #all_bits }
+            }
+
+            /// The set with `bits`, keeping bits that have no flag
+            pub const fn from_bits_retain(bits: #type_tokens) -> Self {
+                Self { bits }
+            }
+
+            pub const fn is_empty(&self) -> bool {
+                self.bits == 0
+            }
+
+            pub fn contains(&self, flag: #name) -> bool {
+                let flag = flag as #type_tokens;
+                self.bits & flag == flag
+            }
+
+            pub fn insert(&mut self, flag: #name) {
+                self.bits |= flag as #type_tokens;
+            }
+
+            pub fn remove(&mut self, flag: #name) {
+                self.bits &= !(flag as #type_tokens);
+            }
+
+            pub fn toggle(&mut self, flag: #name) {
+                self.bits ^= flag as #type_tokens;
+            }
+
+            /// Iterate over the flags in the set, in declaration order
+            pub fn iter(&self) -> impl Iterator<Item = #name> {
+                let bits = self.bits;
+                let flags: [(#type_tokens, #name); #flag_count] = [#((#flag_values, #name::#flag_idents)),*];
+                flags
+                    .into_iter()
+                    .filter_map(move |(value, flag)| (bits & value == value).then_some(flag))
+            }
+        }
+
+        impl ::core::fmt::Debug for #set_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(concat!(stringify!(#set_name), "("))?;
+                let mut first = true;
+                #(
+                    if self.bits & #flag_values == #flag_values {
+                        if !first {
+                            f.write_str(" | ")?;
+                        }
+                        f.write_str(#flag_names)?;
+                        first = false;
+                    }
+                )*
+                let unknown = self.bits & !#all_bits;
+                if unknown != 0 {
+                    if !first {
+                        f.write_str(" | ")?;
+                    }
+                    write!(f, "{:#x}", unknown)?;
+                } else if first {
+                    f.write_str("empty")?;
+                }
+                f.write_str(")")
+            }
+        }
+
+        impl ::core::convert::From<#name> for #set_name {
+            fn from(flag: #name) -> Self {
+                Self { bits: flag as #type_tokens }
+            }
+        }
+
+        impl ::core::iter::FromIterator<#name> for #set_name {
+            fn from_iter<__I: ::core::iter::IntoIterator<Item = #name>>(iter: __I) -> Self {
+                let mut set = Self::empty();
+                for flag in iter {
+                    set.insert(flag);
+                }
+                set
+            }
+        }
+
+        impl ::core::ops::BitOr for #set_name {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self {
+                Self { bits: self.bits | rhs.bits }
+            }
+        }
+
+        impl ::core::ops::BitOr<#name> for #set_name {
+            type Output = Self;
+
+            fn bitor(self, rhs: #name) -> Self {
+                Self { bits: self.bits | rhs as #type_tokens }
+            }
+        }
+
+        impl ::core::ops::BitAnd for #set_name {
+            type Output = Self;
+
+            fn bitand(self, rhs: Self) -> Self {
+                Self { bits: self.bits & rhs.bits }
+            }
+        }
+
+        impl ::core::ops::BitXor for #set_name {
+            type Output = Self;
+
+            fn bitxor(self, rhs: Self) -> Self {
+                Self { bits: self.bits ^ rhs.bits }
+            }
+        }
+
+        impl ::core::ops::Sub for #set_name {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self { bits: self.bits & !rhs.bits }
+            }
+        }
+
+        impl ::bebytes::BeBytes for #set_name {
+            #[inline(always)]
+            fn field_size() -> usize {
+                #byte_size
+            }
+
+            #[inline]
+            fn try_from_be_bytes(bytes: &[u8]) -> ::core::result::Result<(Self, usize), ::bebytes::BeBytesError> {
+                if bytes.len() < #byte_size {
+                    return Err(::bebytes::BeBytesError::InsufficientData {
+                        expected: #byte_size,
+                        actual: bytes.len(),
+                    });
+                }
+                let value = #be_read;
+                Ok((#decode, #byte_size))
+            }
+
+            #[inline]
+            fn try_from_le_bytes(bytes: &[u8]) -> ::core::result::Result<(Self, usize), ::bebytes::BeBytesError> {
+                if bytes.len() < #byte_size {
+                    return Err(::bebytes::BeBytesError::InsufficientData {
+                        expected: #byte_size,
+                        actual: bytes.len(),
+                    });
+                }
+                let value = #le_read;
+                Ok((#decode, #byte_size))
+            }
+
+            #[inline]
+            fn to_be_bytes(&self) -> ::bebytes::Vec<u8> {
+                let mut buf = ::bebytes::BytesMut::with_capacity(#byte_size);
+                let val = self.bits;
+                #be_vec_write;
+                buf.freeze().to_vec()
+            }
+
+            #[inline]
+            fn to_le_bytes(&self) -> ::bebytes::Vec<u8> {
+                let mut buf = ::bebytes::BytesMut::with_capacity(#byte_size);
+                let val = self.bits;
+                #le_vec_write;
+                buf.freeze().to_vec()
+            }
+
+            #[inline]
+            fn encode_be_to<__B: ::bebytes::BufMut>(&self, buf: &mut __B) -> ::core::result::Result<(), ::bebytes::BeBytesError> {
+                if buf.remaining_mut() < #byte_size {
+                    return Err(::bebytes::BeBytesError::InsufficientData {
+                        expected: #byte_size,
+                        actual: buf.remaining_mut(),
+                    });
+                }
+                let val = self.bits;
+                #be_write;
+                Ok(())
+            }
+
+            #[inline]
+            fn encode_le_to<__B: ::bebytes::BufMut>(&self, buf: &mut __B) -> ::core::result::Result<(), ::bebytes::BeBytesError> {
+                if buf.remaining_mut() < #byte_size {
+                    return Err(::bebytes::BeBytesError::InsufficientData {
+                        expected: #byte_size,
+                        actual: buf.remaining_mut(),
+                    });
+                }
+                let val = self.bits;
+                #le_write;
+                Ok(())
+            }
+        }
+    }
+}
+
 #[allow(clippy::too_many_lines)]
 #[proc_macro_derive(
     BeBytes,
@@ -255,6 +903,8 @@ fn generate_bit_field_optimized_methods(
 pub fn derive_be_bytes(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = input.ident.clone();
+    let generics = utils::add_be_bytes_bounds(&input.generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let my_trait_path: syn::Path = syn::parse_quote!(::bebytes::BeBytes);
 
     let mut field_limit_check = Vec::new();
@@ -278,11 +928,57 @@ pub fn derive_be_bytes(input: TokenStream) -> TokenStream {
 
     match input.data {
         Data::Struct(data) => match data.fields {
-            Fields::Named(fields) => {
-                let struct_field_names = fields.named.iter().map(|f| &f.ident).collect::<Vec<_>>();
+            fields @ (Fields::Named(_) | Fields::Unnamed(_)) => {
+                let struct_attrs = match attrs::parse_struct_attributes(&input.attrs) {
+                    Ok(struct_attrs) => struct_attrs,
+                    Err(e) => return e.to_compile_error().into(),
+                };
+                if struct_attrs.bits_struct {
+                    if struct_attrs.transparent
+                        || struct_attrs.bit_order.is_some()
+                        || struct_attrs.c_layout
+                        || struct_attrs.endian.is_some()
+                    {
+                        return syn::Error::new_spanned(
+                            &name,
+                            "#[bebytes(bits_struct)] takes the bit order of the struct that embeds it and cannot be combined with `transparent`, `bit_order`, `c_layout` or `endian`",
+                        )
+                        .to_compile_error()
+                        .into();
+                    }
+                    return bits_struct::generate_bits_struct(&name, &input.generics, &fields)
+                        .unwrap_or_else(syn::Error::into_compile_error)
+                        .into();
+                }
+                if struct_attrs.c_layout
+                    && (struct_attrs.transparent || struct_attrs.bit_order.is_some())
+                {
+                    return syn::Error::new_spanned(
+                        &name,
+                        "#[bebytes(c_layout)] follows the byte order of each method and cannot be combined with `transparent` or `bit_order`",
+                    )
+                    .to_compile_error()
+                    .into();
+                }
+                if struct_attrs.transparent {
+                    if let Err(e) = attrs::validate_transparent(&fields) {
+                        return e.to_compile_error().into();
+                    }
+                }
+
+                let struct_field_names = fields
+                    .iter()
+                    .enumerate()
+                    .map(|(index, field)| utils::field_binding(index, field))
+                    .collect::<Vec<_>>();
+                let constructor = utils::fields_constructor(&quote! { Self }, &fields);
 
                 // Analyze struct for optimization opportunities
-                let optimization_analysis = optimization::StructAnalysis::analyze_struct(&fields);
+                let mut optimization_analysis =
+                    optimization::StructAnalysis::analyze_struct(&fields);
+                if struct_attrs.c_layout {
+                    optimization_analysis = optimization_analysis.without_raw_pointer();
+                }
 
                 // Generate big-endian implementation
                 let mut be_context = structs::StructContext {
@@ -295,7 +991,10 @@ pub fn derive_be_bytes(input: TokenStream) -> TokenStream {
                     named_fields: &mut named_fields,
                     fields: &fields,
                     endianness: Endianness::Big,
+                    bit_order: struct_attrs.bit_order,
+                    c_layout: struct_attrs.c_layout,
                     has_bit_fields: &mut has_bit_fields,
+                    field_access: structs::FieldAccess::SelfMember,
                 };
                 structs::handle_struct(&mut be_context);
 
@@ -313,7 +1012,10 @@ pub fn derive_be_bytes(input: TokenStream) -> TokenStream {
                     named_fields: &mut le_named_fields,
                     fields: &fields,
                     endianness: Endianness::Little,
+                    bit_order: struct_attrs.bit_order,
+                    c_layout: struct_attrs.c_layout,
                     has_bit_fields: &mut le_dummy_has_bit_fields,
+                    field_access: structs::FieldAccess::SelfMember,
                 };
                 structs::handle_struct(&mut le_context);
 
@@ -325,11 +1027,14 @@ pub fn derive_be_bytes(input: TokenStream) -> TokenStream {
                     .into();
                 }
 
-                let constructor_arg_list = fields.named.iter().map(|f| {
-                    let field_ident = &f.ident;
-                    let field_type = &f.ty;
-                    quote! { #field_ident: #field_type }
-                });
+                let constructor_arg_list =
+                    fields
+                        .iter()
+                        .zip(&struct_field_names)
+                        .map(|(f, field_ident)| {
+                            let field_type = &f.ty;
+                            quote! { #field_ident: #field_type }
+                        });
 
                 // Generate direct writing methods for all structs
                 // Bit field structs get stack-allocated optimization when possible
@@ -345,7 +1050,7 @@ pub fn derive_be_bytes(input: TokenStream) -> TokenStream {
                 } else {
                     quote! {
                         #[inline]
-                        fn encode_be_to<B: ::bebytes::BufMut>(&self, buf: &mut B) -> ::core::result::Result<(), ::bebytes::BeBytesError> {
+                        fn encode_be_to<__B: ::bebytes::BufMut>(&self, buf: &mut __B) -> ::core::result::Result<(), ::bebytes::BeBytesError> {
                             let required_capacity = Self::field_size();
                             if buf.remaining_mut() < required_capacity {
                                 return Err(::bebytes::BeBytesError::InsufficientData {
@@ -363,7 +1068,7 @@ pub fn derive_be_bytes(input: TokenStream) -> TokenStream {
                         }
 
                         #[inline]
-                        fn encode_le_to<B: ::bebytes::BufMut>(&self, buf: &mut B) -> ::core::result::Result<(), ::bebytes::BeBytesError> {
+                        fn encode_le_to<__B: ::bebytes::BufMut>(&self, buf: &mut __B) -> ::core::result::Result<(), ::bebytes::BeBytesError> {
                             let required_capacity = Self::field_size();
                             if buf.remaining_mut() < required_capacity {
                                 return Err(::bebytes::BeBytesError::InsufficientData {
@@ -391,9 +1096,10 @@ pub fn derive_be_bytes(input: TokenStream) -> TokenStream {
                 let smart_method_selection =
                     optimization::generate_smart_method_selection(&optimization_analysis);
                 let buffer_reuse_helpers = optimization::generate_buffer_reuse_helpers();
+                let default_endian_methods = generate_default_endian_methods(struct_attrs.endian);
                 let expanded = quote! {
                     #performance_docs
-                    impl #my_trait_path for #name {
+                    impl #impl_generics #my_trait_path for #name #ty_generics #where_clause {
                         #[inline(always)]
                         fn field_size() -> usize {
                             let mut bit_sum = 0;
@@ -413,9 +1119,7 @@ pub fn derive_be_bytes(input: TokenStream) -> TokenStream {
                             let mut end_byte_index = 0;
                             let buffer_size = bytes.len();
                             #(#be_field_parsing)*
-                            Ok((Self {
-                                #( #struct_field_names, )*
-                            }, usize::div_ceil(_bit_sum as usize, 8)))
+                            Ok((#constructor, usize::div_ceil(_bit_sum as usize, 8)))
                         }
 
                         #[inline]
@@ -450,9 +1154,7 @@ pub fn derive_be_bytes(input: TokenStream) -> TokenStream {
                             let mut end_byte_index = 0;
                             let buffer_size = bytes.len();
                             #(#le_field_parsing)*
-                            Ok((Self {
-                                #( #struct_field_names, )*
-                            }, usize::div_ceil(_bit_sum as usize, 8)))
+                            Ok((#constructor, usize::div_ceil(_bit_sum as usize, 8)))
                         }
 
                         #[inline]
@@ -480,15 +1182,15 @@ pub fn derive_be_bytes(input: TokenStream) -> TokenStream {
 
                     }
 
-                    impl #name {
+                    impl #impl_generics #name #ty_generics #where_clause {
                         #[allow(clippy::too_many_arguments)]
                         pub fn new(#(#constructor_arg_list,)*) -> Self {
                             #(#field_limit_check)*
-                            Self {
-                                #( #struct_field_names, )*
-                            }
+                            #constructor
                         }
 
+                        #default_endian_methods
+
                         // Raw pointer methods for ultra-high-performance encoding
                         #raw_pointer_methods
 
@@ -503,16 +1205,26 @@ pub fn derive_be_bytes(input: TokenStream) -> TokenStream {
                     }
                 };
 
+                let transparent_conversions = if struct_attrs.transparent {
+                    generate_transparent_conversions(&name, &generics, &fields)
+                } else {
+                    quote! {}
+                };
+
                 let output = quote! {
                     #expanded
+                    #transparent_conversions
                     #(#errors)*
                 };
 
                 output.into()
             }
-            field => {
-                let error = syn::Error::new(field.span(), "Only named fields are supported")
-                    .to_compile_error();
+            Fields::Unit => {
+                let error = syn::Error::new(
+                    name.span(),
+                    "Unit structs are not supported. Add at least one field",
+                )
+                .to_compile_error();
                 quote! {
                     #error
                 }
@@ -520,40 +1232,113 @@ pub fn derive_be_bytes(input: TokenStream) -> TokenStream {
             }
         },
         Data::Enum(data_enum) => {
+            if enums::is_data_enum(&data_enum) {
+                return generate_tagged_enum(&name, &generics, &input.attrs, &data_enum).into();
+            }
+
             let mut is_flags_enum = false;
             let mut explicit_flag_type: Option<enums::FlagType> = None;
+            let mut unknown_bits: Option<enums::UnknownBits> = None;
+            let mut introspection = EnumIntrospection {
+                enabled: false,
+                from_str: true,
+                display: true,
+            };
+            let mut attribute_errors = Vec::new();
 
             for attr in &input.attrs {
                 if attr.path().is_ident("bebytes") {
-                    let _ = attr.parse_nested_meta(|meta| {
+                    let parsed = attr.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("unknown_bits") {
+                            let policy: syn::LitStr = meta.value()?.parse()?;
+                            unknown_bits = Some(enums::UnknownBits::from_lit(&policy)?);
+                            return Ok(());
+                        }
+                        if meta.path.is_ident("introspect") {
+                            introspection.enabled = true;
+                            return Ok(());
+                        }
+                        if meta.path.is_ident("no_from_str") {
+                            introspection.from_str = false;
+                            return Ok(());
+                        }
+                        if meta.path.is_ident("no_display") {
+                            introspection.display = false;
+                            return Ok(());
+                        }
                         if meta.path.is_ident("flags") {
                             is_flags_enum = true;
                             if meta.input.peek(syn::token::Paren) {
                                 let content;
                                 syn::parenthesized!(content in meta.input);
                                 let type_ident: syn::Ident = content.parse()?;
-                                explicit_flag_type = match type_ident.to_string().as_str() {
-                                    "u8" => Some(enums::FlagType::U8),
-                                    "u16" => Some(enums::FlagType::U16),
-                                    "u32" => Some(enums::FlagType::U32),
-                                    "u64" => Some(enums::FlagType::U64),
-                                    "u128" => Some(enums::FlagType::U128),
-                                    _ => {
-                                        return Err(syn::Error::new(
+                                explicit_flag_type = Some(
+                                    enums::FlagType::from_ident(&type_ident).ok_or_else(|| {
+                                        syn::Error::new(
                                             type_ident.span(),
                                             "Invalid flag type. Expected u8, u16, u32, u64, or u128",
-                                        ));
-                                    }
-                                };
+                                        )
+                                    })?,
+                                );
                             }
                         }
                         Ok(())
                     });
+                    if let Err(e) = parsed {
+                        attribute_errors.push(e.to_compile_error());
+                    }
                 }
             }
+            if unknown_bits.is_some() && !is_flags_enum {
+                attribute_errors.push(
+                    syn::Error::new(
+                        name.span(),
+                        "unknown_bits only applies to #[bebytes(flags)] enums",
+                    )
+                    .to_compile_error(),
+                );
+            }
 
-            let (_, _, _, _, discriminants, enum_errors, detected_flag_type) =
-                enums::handle_enum(Vec::new(), data_enum.clone(), None);
+            let repr = attrs::parse_repr_type(&input.attrs);
+            let (
+                _,
+                _,
+                _,
+                _,
+                discriminants,
+                mut enum_errors,
+                detected_flag_type,
+                other_variant,
+                range_checks,
+            ) = enums::handle_enum(attribute_errors, data_enum.clone(), None, repr);
+
+            if is_flags_enum {
+                for variant in &data_enum.variants {
+                    if let Some((_, expr)) = &variant.discriminant {
+                        if enums::evaluate_discriminant(expr).is_none() {
+                            enum_errors.push(
+                                syn::Error::new_spanned(
+                                    expr,
+                                    "Flag enum discriminants must be literals or arithmetic on literals",
+                                )
+                                .to_compile_error(),
+                            );
+                        }
+                    }
+                }
+                // Flag operators cast variants to integers, which needs unit variants
+                for variant in &data_enum.variants {
+                    if !matches!(variant.fields, Fields::Unit) {
+                        enum_errors.push(
+                            syn::Error::new(
+                                variant.ident.span(),
+                                "#[bebytes(other)] and #[bebytes(range = ..)] cannot be combined with #[bebytes(flags)]",
+                            )
+                            .to_compile_error(),
+                        );
+                    }
+                }
+            }
 
             if !enum_errors.is_empty() {
                 return quote! {
@@ -578,74 +1363,70 @@ pub fn derive_be_bytes(input: TokenStream) -> TokenStream {
                 }
             }
 
+            let introspection = generate_enum_introspection(&name, &data_enum, introspection);
             let flag_type = explicit_flag_type.unwrap_or(detected_flag_type);
-            let (from_be_bytes_arms, to_be_bytes_arms, _, try_from_arms, _, _, _) =
-                enums::handle_enum(Vec::new(), data_enum.clone(), Some(flag_type));
-            let (from_le_bytes_arms, to_le_bytes_arms, _, _, _, _, _) =
-                enums::handle_enum(Vec::new(), data_enum, Some(flag_type));
+            let (from_be_bytes_arms, to_be_bytes_arms, _, try_from_arms, _, _, _, _, _) =
+                enums::handle_enum(Vec::new(), data_enum.clone(), Some(flag_type), repr);
+            let (from_le_bytes_arms, to_le_bytes_arms, _, _, _, _, _, _, _) =
+                enums::handle_enum(Vec::new(), data_enum, Some(flag_type), repr);
 
             let type_tokens = flag_type.type_tokens();
             let byte_size = flag_type.byte_size();
             let byte_size_lit = syn::LitInt::new(&byte_size.to_string(), Span::call_site());
 
-            let (big_endian_read, little_endian_read, big_endian_write, little_endian_write) =
-                match flag_type {
-                    enums::FlagType::U8 => (
-                        quote! { bytes[0] },
-                        quote! { bytes[0] },
-                        quote! { put_u8 },
-                        quote! { put_u8 },
-                    ),
-                    enums::FlagType::U16 => (
-                        quote! { u16::from_be_bytes([bytes[0], bytes[1]]) },
-                        quote! { u16::from_le_bytes([bytes[0], bytes[1]]) },
-                        quote! { put_u16 },
-                        quote! { put_u16_le },
-                    ),
-                    enums::FlagType::U32 => (
-                        quote! { u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) },
-                        quote! { u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) },
-                        quote! { put_u32 },
-                        quote! { put_u32_le },
-                    ),
-                    enums::FlagType::U64 => (
-                        quote! { u64::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7]]) },
-                        quote! { u64::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7]]) },
-                        quote! { put_u64 },
-                        quote! { put_u64_le },
-                    ),
-                    enums::FlagType::U128 => (
-                        quote! { u128::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7], bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15]]) },
-                        quote! { u128::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7], bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15]]) },
-                        quote! { put_u128 },
-                        quote! { put_u128_le },
-                    ),
-                };
-
-            let error_expr = if flag_type == enums::FlagType::U8 {
-                quote! {
+            let big_endian_read = flag_type.read_tokens(Endianness::Big);
+            let little_endian_read = flag_type.read_tokens(Endianness::Little);
+            let big_endian_vec_write = flag_type.put_tokens(Endianness::Big, &quote! { &mut buf });
+            let little_endian_vec_write =
+                flag_type.put_tokens(Endianness::Little, &quote! { &mut buf });
+            let big_endian_write = flag_type.put_tokens(Endianness::Big, &quote! { buf });
+            let little_endian_write = flag_type.put_tokens(Endianness::Little, &quote! { buf });
+
+            // Errors report the raw wire value, so signed values show their unsigned bit pattern
+            let error_expr = match flag_type {
+                enums::FlagType::U8 => quote! {
                     Err(::bebytes::BeBytesError::InvalidDiscriminant {
                         value,
                         type_name: stringify!(#name),
                     })
-                }
-            } else {
-                quote! {
-                    Err(::bebytes::BeBytesError::InvalidDiscriminantLarge {
-                        value: value as u128,
+                },
+                enums::FlagType::I8 => quote! {
+                    Err(::bebytes::BeBytesError::InvalidDiscriminant {
+                        value: value as u8,
                         type_name: stringify!(#name),
                     })
+                },
+                _ => {
+                    let unsigned_tokens = flag_type.unsigned().type_tokens();
+                    quote! {
+                        Err(::bebytes::BeBytesError::InvalidDiscriminantLarge {
+                            value: value as #unsigned_tokens as u128,
+                            type_name: stringify!(#name),
+                        })
+                    }
                 }
             };
 
+            // Unrecognized values are kept by the catch-all variant, if there is one
+            let (try_from_fallback, from_bytes_fallback) = match &other_variant {
+                Some(other) => (
+                    quote! { Ok(Self::#other(value)) },
+                    quote! { Ok((Self::#other(value), #byte_size_lit)) },
+                ),
+                None => (error_expr.clone(), error_expr),
+            };
+
             let expanded = quote! {
+                #introspection
+                #(#range_checks)*
+
                 impl ::core::convert::TryFrom<#type_tokens> for #name {
                     type Error = ::bebytes::BeBytesError;
 
                     fn try_from(value: #type_tokens) -> ::core::result::Result<Self, Self::Error> {
                         match value {
                             #(#try_from_arms)*
-                            _ => #error_expr,
+                            _ => #try_from_fallback,
                         }
                     }
                 }
@@ -667,7 +1448,7 @@ pub fn derive_be_bytes(input: TokenStream) -> TokenStream {
                         let value = #big_endian_read;
                         match value {
                             #(#from_be_bytes_arms)*
-                            _ => #error_expr,
+                            _ => #from_bytes_fallback,
                         }
                     }
 
@@ -677,7 +1458,7 @@ pub fn derive_be_bytes(input: TokenStream) -> TokenStream {
                         let val = match self {
                             #(#to_be_bytes_arms)*
                         };
-                        ::bebytes::BufMut::#big_endian_write(&mut buf, val);
+                        #big_endian_vec_write;
                         buf.freeze().to_vec()
                     }
 
@@ -692,7 +1473,7 @@ pub fn derive_be_bytes(input: TokenStream) -> TokenStream {
                         let value = #little_endian_read;
                         match value {
                             #(#from_le_bytes_arms)*
-                            _ => #error_expr,
+                            _ => #from_bytes_fallback,
                         }
                     }
 
@@ -702,7 +1483,7 @@ pub fn derive_be_bytes(input: TokenStream) -> TokenStream {
                         let val = match self {
                             #(#to_le_bytes_arms)*
                         };
-                        ::bebytes::BufMut::#little_endian_write(&mut buf, val);
+                        #little_endian_vec_write;
                         buf.freeze().to_vec()
                     }
 
@@ -717,7 +1498,7 @@ pub fn derive_be_bytes(input: TokenStream) -> TokenStream {
                     }
 
                     #[inline]
-                    fn encode_be_to<B: ::bebytes::BufMut>(&self, buf: &mut B) -> ::core::result::Result<(), ::bebytes::BeBytesError> {
+                    fn encode_be_to<__B: ::bebytes::BufMut>(&self, buf: &mut __B) -> ::core::result::Result<(), ::bebytes::BeBytesError> {
                         if buf.remaining_mut() < #byte_size_lit {
                             return Err(::bebytes::BeBytesError::InsufficientData {
                                 expected: #byte_size_lit,
@@ -727,12 +1508,12 @@ pub fn derive_be_bytes(input: TokenStream) -> TokenStream {
                         let val = match self {
                             #(#to_be_bytes_arms)*
                         };
-                        buf.#big_endian_write(val);
+                        #big_endian_write;
                         Ok(())
                     }
 
                     #[inline]
-                    fn encode_le_to<B: ::bebytes::BufMut>(&self, buf: &mut B) -> ::core::result::Result<(), ::bebytes::BeBytesError> {
+                    fn encode_le_to<__B: ::bebytes::BufMut>(&self, buf: &mut __B) -> ::core::result::Result<(), ::bebytes::BeBytesError> {
                         if buf.remaining_mut() < #byte_size_lit {
                             return Err(::bebytes::BeBytesError::InsufficientData {
                                 expected: #byte_size_lit,
@@ -742,7 +1523,7 @@ pub fn derive_be_bytes(input: TokenStream) -> TokenStream {
                         let val = match self {
                             #(#to_le_bytes_arms)*
                         };
-                        buf.#little_endian_write(val);
+                        #little_endian_write;
                         Ok(())
                     }
                 }
@@ -772,8 +1553,16 @@ pub fn derive_be_bytes(input: TokenStream) -> TokenStream {
                 }
 
                 if validation_errors.is_empty() {
+                    let flag_set = generate_flag_set(
+                        &name,
+                        &input.vis,
+                        flag_type,
+                        &discriminants,
+                        unknown_bits.unwrap_or_default(),
+                    );
                     quote! {
                         #expanded
+                        #flag_set
 
                         impl core::ops::BitOr for #name {
                             type Output = #type_tokens;
@@ -3,7 +3,7 @@
 //! This module analyzes struct characteristics to determine the optimal
 //! serialization method and provides performance hints.
 
-use syn::{FieldsNamed, Type};
+use syn::{Fields, Type};
 
 #[cfg(feature = "std")]
 use std::vec::Vec;
@@ -58,14 +58,14 @@ pub struct PerformanceHint {
 impl StructAnalysis {
     /// Analyze a struct's fields to determine optimization characteristics
     #[allow(clippy::too_many_lines)]
-    pub fn analyze_struct(fields: &FieldsNamed) -> Self {
+    pub fn analyze_struct(fields: &Fields) -> Self {
         let mut size = Some(0usize);
         let mut has_bit_fields = false;
         let mut has_vectors = false;
         let mut has_strings = false;
 
         // Handle empty structs (no fields)
-        if fields.named.is_empty() {
+        if fields.is_empty() {
             return Self {
                 size: Some(0),
                 has_bit_fields: false,
@@ -81,7 +81,7 @@ impl StructAnalysis {
             };
         }
 
-        for field in &fields.named {
+        for field in fields {
             // Check for bit fields
             if field.attrs.iter().any(|attr| attr.path().is_ident("bits")) {
                 has_bit_fields = true;
@@ -98,8 +98,10 @@ impl StructAnalysis {
                             "String" => has_strings = true,
                             _ => {
                                 // Try to get primitive type size
-                                if let Ok(field_size) =
-                                    crate::utils::get_primitive_type_size(&field.ty)
+                                if let Some(field_size) =
+                                    crate::attrs::parse_byte_width(&field.attrs).or_else(|| {
+                                        crate::utils::get_primitive_type_size(&field.ty).ok()
+                                    })
                                 {
                                     if let Some(current_size) = size {
                                         size = Some(current_size + field_size);
@@ -111,27 +113,11 @@ impl StructAnalysis {
                         }
                     }
                 }
-                Type::Array(array_type) => {
-                    // Handle byte arrays
-                    if let Type::Path(element_type) = &*array_type.elem {
-                        if element_type.path.is_ident("u8") {
-                            if let syn::Expr::Lit(syn::ExprLit {
-                                lit: syn::Lit::Int(len),
-                                ..
-                            }) = &array_type.len
-                            {
-                                if let Ok(array_len) = len.base10_parse::<usize>() {
-                                    if let Some(current_size) = size {
-                                        size = Some(current_size + array_len);
-                                    }
-                                } else {
-                                    size = None;
-                                }
-                            } else {
-                                size = None;
-                            }
-                        } else {
-                            size = None;
+                Type::Array(_) => {
+                    // Arrays of primitives have a fixed size
+                    if let Some(array_size) = crate::utils::get_fixed_type_size(&field.ty) {
+                        if let Some(current_size) = size {
+                            size = Some(current_size + array_size);
                         }
                     } else {
                         size = None;
@@ -159,24 +145,7 @@ impl StructAnalysis {
             OptimizationMethod::Standard
         };
 
-        // Generate performance hint
-        let performance_hint = match recommended_method {
-            OptimizationMethod::RawPointer => PerformanceHint {
-                improvement_factor: 5.4,
-                allocation_pattern: "Zero allocations (stack only)",
-                recommendation: "Optimal: Use raw pointer methods for maximum performance",
-            },
-            OptimizationMethod::BytesBuffer => PerformanceHint {
-                improvement_factor: 2.3,
-                allocation_pattern: "Single allocation with reuse potential",
-                recommendation: "Good: Use Bytes buffer methods for better performance",
-            },
-            OptimizationMethod::Standard => PerformanceHint {
-                improvement_factor: 1.0,
-                allocation_pattern: "Standard Vec allocation",
-                recommendation: "Standard: Complex types require Vec approach",
-            },
-        };
+        let performance_hint = performance_hint(&recommended_method);
 
         Self {
             size,
@@ -189,6 +158,17 @@ impl StructAnalysis {
         }
     }
 
+    /// The same analysis for a struct whose encoding isn't its fields back to back, such as
+    /// a padded `c_layout` struct, which rules out raw pointer encoding
+    pub fn without_raw_pointer(mut self) -> Self {
+        self.supports_raw_pointer = false;
+        if self.recommended_method == OptimizationMethod::RawPointer {
+            self.recommended_method = OptimizationMethod::BytesBuffer;
+            self.performance_hint = performance_hint(&self.recommended_method);
+        }
+        self
+    }
+
     /// Generate compile-time performance documentation
     pub fn generate_performance_docs(&self) -> proc_macro2::TokenStream {
         let improvement = self.performance_hint.improvement_factor;
@@ -272,6 +252,27 @@ impl StructAnalysis {
     }
 }
 
+/// Expected performance of an optimization method
+fn performance_hint(method: &OptimizationMethod) -> PerformanceHint {
+    match method {
+        OptimizationMethod::RawPointer => PerformanceHint {
+            improvement_factor: 5.4,
+            allocation_pattern: "Zero allocations (stack only)",
+            recommendation: "Optimal: Use raw pointer methods for maximum performance",
+        },
+        OptimizationMethod::BytesBuffer => PerformanceHint {
+            improvement_factor: 2.3,
+            allocation_pattern: "Single allocation with reuse potential",
+            recommendation: "Good: Use Bytes buffer methods for better performance",
+        },
+        OptimizationMethod::Standard => PerformanceHint {
+            improvement_factor: 1.0,
+            allocation_pattern: "Standard Vec allocation",
+            recommendation: "Standard: Complex types require Vec approach",
+        },
+    }
+}
+
 /// Generate buffer reuse helper methods for batch operations
 pub fn generate_buffer_reuse_helpers() -> proc_macro2::TokenStream {
     quote::quote! {
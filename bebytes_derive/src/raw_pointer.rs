@@ -1,10 +1,10 @@
 use quote::quote;
-use syn::{FieldsNamed, Type};
+use syn::{Fields, Type};
 
 /// Generate raw pointer-based direct writing for maximum performance
 /// This approach eliminates all abstraction overhead by writing directly to memory
 pub fn generate_raw_pointer_writing(
-    field_name: &syn::Ident,
+    field_name: &syn::Member,
     field_type: &Type,
     endianness: crate::consts::Endianness,
     offset_var: &syn::Ident,
@@ -89,9 +89,45 @@ pub fn generate_raw_pointer_writing(
     }
 }
 
+/// Generate raw pointer writing for a `#[bebytes(bytes = N)]` integer, keeping its `width`
+/// least significant bytes
+pub fn generate_raw_pointer_narrow_writing(
+    field_name: &syn::Member,
+    field_type: &Type,
+    width: usize,
+    endianness: crate::consts::Endianness,
+    offset_var: &syn::Ident,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let int_type = match field_type {
+        Type::Path(tp) => tp
+            .path
+            .get_ident()
+            .and_then(crate::enums::FlagType::from_int_ident),
+        _ => None,
+    }
+    .ok_or_else(|| syn::Error::new_spanned(field_type, "bytes = N applies to integer fields"))?;
+    let range_check = crate::functional::pure_helpers::create_byte_width_check(
+        &quote! { self.#field_name },
+        &quote!(#field_name).to_string(),
+        int_type,
+        width,
+    );
+    let to_bytes_method = crate::utils::get_to_bytes_method(endianness);
+    let start = match endianness {
+        crate::consts::Endianness::Big => int_type.byte_size() - width,
+        crate::consts::Endianness::Little => 0,
+    };
+    Ok(quote! {
+        #range_check
+        let field_bytes = self.#field_name.#to_bytes_method();
+        ::core::ptr::copy_nonoverlapping(field_bytes.as_ptr().add(#start), ptr.add(#offset_var), #width);
+        #offset_var += #width;
+    })
+}
+
 /// Generate raw pointer writing for byte arrays
 pub fn generate_raw_pointer_array_writing(
-    field_name: &syn::Ident,
+    field_name: &syn::Member,
     array_length: usize,
     offset_var: &syn::Ident,
 ) -> proc_macro2::TokenStream {
@@ -101,16 +137,87 @@ pub fn generate_raw_pointer_array_writing(
     }
 }
 
+/// Generate raw pointer writing for one element of an array of primitives, recursing
+/// into nested arrays. `element` is the element's value, or a reference for arrays.
+fn generate_raw_pointer_element_writing(
+    element: &proc_macro2::TokenStream,
+    element_type: &Type,
+    endianness: crate::consts::Endianness,
+    offset_var: &syn::Ident,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    if let Type::Array(array_type) = element_type {
+        let inner_type = &*array_type.elem;
+        let inner_binding = if matches!(inner_type, Type::Array(_)) {
+            quote! { __element }
+        } else {
+            quote! { &__element }
+        };
+        let inner_writing = generate_raw_pointer_element_writing(
+            &quote! { __element },
+            inner_type,
+            endianness,
+            offset_var,
+        )?;
+        return Ok(quote! {
+            for #inner_binding in #element.iter() {
+                #inner_writing
+            }
+        });
+    }
+
+    let element_size = crate::utils::get_primitive_type_size(element_type)?;
+    let to_bytes_method = crate::utils::get_to_bytes_method(endianness);
+    if element_size == 1 {
+        return Ok(quote! {
+            *ptr.add(#offset_var) = #element as u8;
+            #offset_var += 1;
+        });
+    }
+    let element_bytes = match element_type {
+        Type::Path(tp) if tp.path.is_ident("char") => {
+            quote! { (#element as u32).#to_bytes_method() }
+        }
+        _ => quote! { #element.#to_bytes_method() },
+    };
+    Ok(quote! {
+        let element_bytes = #element_bytes;
+        ::core::ptr::copy_nonoverlapping(element_bytes.as_ptr(), ptr.add(#offset_var), #element_size);
+        #offset_var += #element_size;
+    })
+}
+
+/// Generate raw pointer writing for a `#[bebytes(word_order = "..")]` value
+pub fn generate_raw_pointer_word_order_writing(
+    field_name: &syn::Member,
+    field_type: &Type,
+    word_order: crate::word_order::WordOrder,
+    offset_var: &syn::Ident,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let field_size = crate::utils::get_primitive_type_size(field_type)?;
+    let to_bytes_method = crate::utils::get_to_bytes_method(word_order.endianness());
+    let value = if word_order.swaps_word_bytes() {
+        crate::word_order::swap_word_bytes(&quote! { self.#field_name }, field_type)
+    } else {
+        quote! { self.#field_name }
+    };
+
+    Ok(quote! {
+        let field_bytes = #value.#to_bytes_method();
+        ::core::ptr::copy_nonoverlapping(field_bytes.as_ptr(), ptr.add(#offset_var), #field_size);
+        #offset_var += #field_size;
+    })
+}
+
 /// Generate raw pointer writing for an entire struct
 pub fn generate_raw_pointer_struct_writing(
-    fields: &FieldsNamed,
+    fields: &Fields,
     endianness: crate::consts::Endianness,
 ) -> Result<proc_macro2::TokenStream, syn::Error> {
     let mut field_writing_code = Vec::new();
     let offset_var = syn::Ident::new("offset", proc_macro2::Span::call_site());
 
-    for field in &fields.named {
-        let field_name = field.ident.as_ref().unwrap();
+    for (index, field) in fields.iter().enumerate() {
+        let field_name = &crate::utils::field_member(index, field);
         let field_type = &field.ty;
 
         // Check if this is a bit field (has #[bits(N)] attribute)
@@ -121,26 +228,46 @@ pub fn generate_raw_pointer_struct_writing(
             continue;
         }
 
+        // A field with its own byte order keeps it in both methods
+        let endianness = crate::attrs::parse_field_endian(&field.attrs).unwrap_or(endianness);
+
         // Handle primitive types and arrays
-        if let Ok(writing_code) =
+        if let Some(word_order) = crate::attrs::parse_field_word_order(&field.attrs) {
+            field_writing_code.push(generate_raw_pointer_word_order_writing(
+                field_name,
+                field_type,
+                word_order,
+                &offset_var,
+            )?);
+        } else if let Some(width) = crate::attrs::parse_byte_width(&field.attrs) {
+            field_writing_code.push(generate_raw_pointer_narrow_writing(
+                field_name,
+                field_type,
+                width,
+                endianness,
+                &offset_var,
+            )?);
+        } else if let Ok(writing_code) =
             generate_raw_pointer_writing(field_name, field_type, endianness, &offset_var)
         {
             field_writing_code.push(writing_code);
         } else if let Type::Array(array_type) = field_type {
-            // Handle byte arrays
-            if let Type::Path(element_type) = &*array_type.elem {
-                if element_type.path.is_ident("u8") {
-                    if let syn::Expr::Lit(syn::ExprLit {
-                        lit: syn::Lit::Int(len),
-                        ..
-                    }) = &array_type.len
-                    {
-                        let array_len: usize = len.base10_parse()?;
-                        let array_code =
-                            generate_raw_pointer_array_writing(field_name, array_len, &offset_var);
-                        field_writing_code.push(array_code);
-                    }
-                }
+            let Some(array_size) = crate::utils::get_fixed_type_size(field_type) else {
+                continue;
+            };
+            if matches!(&*array_type.elem, Type::Path(element_type) if element_type.path.is_ident("u8"))
+            {
+                // Byte arrays are copied in one go
+                let array_code =
+                    generate_raw_pointer_array_writing(field_name, array_size, &offset_var);
+                field_writing_code.push(array_code);
+            } else {
+                field_writing_code.push(generate_raw_pointer_element_writing(
+                    &quote! { self.#field_name },
+                    field_type,
+                    endianness,
+                    &offset_var,
+                )?);
             }
         }
     }
@@ -1,3 +1,4 @@
+use crate::enums::UnknownBits;
 use crate::functional::pure_helpers::{create_field_accessor, AccessorMode};
 use crate::{attrs, utils};
 use quote::{quote, quote_spanned};
@@ -26,22 +27,37 @@ use std::vec::Vec;
 use alloc::vec::Vec;
 
 enum FieldType {
-    BitsField(usize), // only size, position is auto-calculated
+    BitsField(usize),        // only size, position is auto-calculated
+    BitsArray(usize, usize), // bits per element, array length
     PrimitiveType,
-    Array(usize),                                     // array_length
-    Vector(Option<usize>, Option<Vec<syn::Ident>>),   // size, vec_size_ident
-    String(Option<usize>, Option<Vec<syn::Ident>>),   // size, string_size_ident
+    NarrowInteger(usize, crate::enums::FlagType), // byte width, integer type
+    Array(Option<usize>),                         // array_length, None when generic
+    Vector(Option<usize>, Option<Vec<syn::Ident>>, bool), // size, vec_size_ident, byte_length
+    String(Option<usize>, Option<Vec<syn::Ident>>), // size, string_size_ident
     SizeExpression(crate::size_expr::SizeExpression), // expression-based sizing
-    OptionType,
+    OptionType(Option<usize>, Option<Vec<syn::Ident>>, OptionMode), // size, size_ident of a Vec or String, mode
     CustomType,
+    PhantomData,     // zero-size marker, such as one holding a lifetime parameter
     UntilMarker(u8), // Read Vec<T> until marker byte
     AfterMarker(u8), // Read remaining bytes after marker
     VecOfVecsWithMarker(Option<usize>, Option<Vec<syn::Ident>>, u8), // size, field_path, marker
+    TaggedBody(Vec<syn::Ident>), // data-carrying enum whose tag is the field at this path
+}
+
+/// How an `Option` field encodes whether it holds a value
+enum OptionMode {
+    /// Leading tag of this type: 0 for `None`, 1 for `Some`
+    Tag(crate::enums::FlagType),
+    /// No tag; the value is present when the field at this path is set
+    PresentIf(Vec<syn::Ident>),
+    /// No tag; `None` is encoded as this value of the inner type
+    NoneValue(syn::Expr),
 }
 
 struct FieldContext<'a> {
     field: &'a syn::Field,
     field_name: syn::Ident,
+    field_access: proc_macro2::TokenStream,
     field_type: &'a syn::Type,
     is_last_field: bool,
 }
@@ -57,6 +73,15 @@ pub struct FieldData {
     pub total_size: usize,
 }
 
+/// How generated writing code reaches the value of each field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldAccess {
+    /// Through `self`, as in `self.name` or `self.0`
+    SelfMember,
+    /// Through a reference bound by a match pattern under the field's binding name
+    Binding,
+}
+
 pub struct StructContext<'a> {
     pub field_limit_check: &'a mut Vec<proc_macro2::TokenStream>,
     pub errors: &'a mut Vec<proc_macro2::TokenStream>,
@@ -65,9 +90,14 @@ pub struct StructContext<'a> {
     pub field_writing: &'a mut Vec<proc_macro2::TokenStream>,
     pub direct_writing: &'a mut Vec<proc_macro2::TokenStream>, // New: direct buffer writing
     pub named_fields: &'a mut Vec<proc_macro2::TokenStream>,
-    pub fields: &'a syn::FieldsNamed,
+    pub fields: &'a syn::Fields,
     pub endianness: crate::consts::Endianness,
+    /// Fixed layout of bit fields, `None` to follow `endianness`
+    pub bit_order: Option<crate::consts::Endianness>,
+    /// Pad fields like a C compiler, see `bit_validation::c_layout`
+    pub c_layout: bool,
     pub has_bit_fields: &'a mut bool, // Track if any fields have bit attributes
+    pub field_access: FieldAccess,
 }
 
 fn handle_marker_attribute(
@@ -101,23 +131,42 @@ fn handle_bits_field(
 ) -> Option<FieldType> {
     *has_bit_fields = true;
     if let Some(size) = size {
+        // Arrays pack each element in `size` bits, so the element type is validated instead
+        let (element_type, array_length) = match context.field_type {
+            syn::Type::Array(arr) => {
+                let Some(length) = utils::array_literal_length(arr) else {
+                    errors.push(
+                        syn::Error::new_spanned(&arr.len, "Bit-field arrays need a literal length")
+                            .to_compile_error(),
+                    );
+                    return None;
+                };
+                (&*arr.elem, Some(length))
+            }
+            field_type => (field_type, None),
+        };
         // Validate bit count doesn't exceed capacity (also validates type is bitfield-compatible)
-        if let Ok(max_bits) = utils::get_primitive_type_max_bits(context.field_type) {
+        if let Ok(max_bits) = utils::get_primitive_type_max_bits(element_type) {
             if size > max_bits {
-                errors.push(syn::Error::new(
-                    context.field.span(),
-                    format!("bits attribute specifies {size} bits, but type can only hold {max_bits} bits"),
-                ).to_compile_error());
+                let message = if max_bits == 1 {
+                    format!("bits attribute specifies {size} bits, but a bool flag holds exactly one bit. Use #[bits(1)]")
+                } else {
+                    format!("bits attribute specifies {size} bits, but type can only hold {max_bits} bits")
+                };
+                errors.push(syn::Error::new(context.field.span(), message).to_compile_error());
                 return None;
             }
         } else {
             errors.push(syn::Error::new(
                 context.field_type.span(),
-                "Unsupported type for bits attribute. Only integer types (u8, i8, u16, i16, u32, i32, u64, i64, u128, i128) and char are supported. Note: f32, f64, and bool cannot be used with bit fields",
+                "Unsupported type for bits attribute. Only integer types (u8, i8, u16, i16, u32, i32, u64, i64, u128, i128), char and bool (1 bit) are supported. Note: f32 and f64 cannot be used with bit fields",
             ).to_compile_error());
             return None;
         }
-        return Some(FieldType::BitsField(size));
+        return Some(match array_length {
+            Some(length) => FieldType::BitsArray(size, length),
+            None => FieldType::BitsField(size),
+        });
     }
     // Empty #[bits()] is no longer supported
     errors.push(syn::Error::new(
@@ -163,15 +212,264 @@ fn handle_vec_of_vecs_marker(
     None
 }
 
+// Helper to validate a `#[bebytes(tag_from = ...)]` field
+fn handle_tag_from(
+    context: &FieldContext,
+    tag_path: Vec<syn::Ident>,
+    has_other_attributes: bool,
+    errors: &mut Vec<proc_macro2::TokenStream>,
+) -> Option<FieldType> {
+    if has_other_attributes {
+        errors.push(
+            syn::Error::new_spanned(
+                context.field,
+                "tag_from cannot be combined with bits, size or marker attributes",
+            )
+            .to_compile_error(),
+        );
+        return None;
+    }
+    Some(FieldType::TaggedBody(tag_path))
+}
+
+// Helper to validate a `#[bebytes(bytes = N)]` integer field
+fn handle_byte_width(
+    context: &FieldContext,
+    width: usize,
+    has_other_attributes: bool,
+    errors: &mut Vec<proc_macro2::TokenStream>,
+) -> Option<FieldType> {
+    if has_other_attributes {
+        errors.push(
+            syn::Error::new_spanned(
+                context.field,
+                "bytes cannot be combined with bits, size, marker or bitflags attributes",
+            )
+            .to_compile_error(),
+        );
+        return None;
+    }
+    let int_type = match context.field_type {
+        syn::Type::Path(tp) => tp
+            .path
+            .get_ident()
+            .and_then(crate::enums::FlagType::from_int_ident),
+        _ => None,
+    };
+    match int_type {
+        Some(int_type) if width <= int_type.byte_size() => {
+            Some(FieldType::NarrowInteger(width, int_type))
+        }
+        Some(int_type) => {
+            errors.push(
+                syn::Error::new_spanned(
+                    context.field,
+                    format!(
+                        "bytes = {width} exceeds the {} bytes of {}",
+                        int_type.byte_size(),
+                        int_type.type_name()
+                    ),
+                )
+                .to_compile_error(),
+            );
+            None
+        }
+        None => {
+            errors.push(
+                syn::Error::new(
+                    context.field_type.span(),
+                    "bytes = N applies to integer fields (u16, i32, u64, ...)",
+                )
+                .to_compile_error(),
+            );
+            None
+        }
+    }
+}
+
+// Helper to validate a `#[bebytes(word_order = "..")]` field
+fn handle_word_order(
+    context: &FieldContext,
+    has_other_attributes: bool,
+    errors: &mut Vec<proc_macro2::TokenStream>,
+) -> bool {
+    if has_other_attributes {
+        errors.push(
+            syn::Error::new_spanned(
+                context.field,
+                "word_order cannot be combined with endian, bits, bytes, marker or bitflags attributes",
+            )
+            .to_compile_error(),
+        );
+        return false;
+    }
+    let is_word_type = match context.field_type {
+        syn::Type::Path(tp) => match utils::solve_for_inner_type(tp, "Vec") {
+            Some(syn::Type::Path(element)) => crate::word_order::is_word_type(&element),
+            Some(_) => false,
+            None => crate::word_order::is_word_type(tp),
+        },
+        _ => false,
+    };
+    if !is_word_type {
+        errors.push(
+            syn::Error::new(
+                context.field_type.span(),
+                "word_order applies to u32, i32, f32, u64, i64 and f64 fields and vectors of them",
+            )
+            .to_compile_error(),
+        );
+    }
+    is_word_type
+}
+
+#[allow(clippy::too_many_lines)]
 fn determine_field_type(
     context: &FieldContext,
-    attrs: &[syn::Attribute],
+    attr_data: crate::functional::AttributeData,
     errors: &mut Vec<proc_macro2::TokenStream>,
     has_bit_fields: &mut bool,
 ) -> Option<FieldType> {
-    let mut bits_attribute_present = false;
-    let (size, vec_size_ident, size_expression, until_marker, after_marker) =
-        attrs::parse_attributes_with_expressions(attrs, &mut bits_attribute_present, errors);
+    let crate::functional::AttributeData {
+        size,
+        field: vec_size_ident,
+        size_expression,
+        is_bits_attribute: bits_attribute_present,
+        until_marker,
+        after_marker,
+        tag_from,
+        bitflags,
+        unknown_bits,
+        byte_length,
+        present_if,
+        none_value,
+        tag_type,
+        byte_width,
+        // Applied through the field's processing context
+        endian,
+        word_order,
+    } = attr_data;
+
+    let option_mode = if present_if.is_some() || none_value.is_some() || tag_type.is_some() {
+        let is_option = matches!(context.field_type, syn::Type::Path(tp)
+            if tp.path.segments.first().is_some_and(|segment| segment.ident == "Option"));
+        let mode = match (present_if, none_value, tag_type) {
+            (Some(path), None, None) => Some(OptionMode::PresentIf(path)),
+            (None, Some(value), None) => Some(OptionMode::NoneValue(value)),
+            (None, None, Some(tag_type)) => Some(OptionMode::Tag(tag_type)),
+            _ => None,
+        };
+        match mode {
+            Some(mode) if is_option && !bits_attribute_present => mode,
+            Some(_) => {
+                errors.push(
+                    syn::Error::new_spanned(
+                        context.field,
+                        "present_if, none_value and tag_type apply to Option fields",
+                    )
+                    .to_compile_error(),
+                );
+                return None;
+            }
+            None => {
+                errors.push(
+                    syn::Error::new_spanned(
+                        context.field,
+                        "Only one of present_if, none_value and tag_type can be used on a field",
+                    )
+                    .to_compile_error(),
+                );
+                return None;
+            }
+        }
+    } else {
+        OptionMode::Tag(crate::enums::FlagType::U8)
+    };
+
+    if byte_length {
+        let is_sized_vector = size.is_some() || vec_size_ident.is_some();
+        let is_vector = matches!(context.field_type, syn::Type::Path(tp)
+            if tp.path.segments.first().is_some_and(|segment| segment.ident == "Vec"));
+        if !is_vector || !is_sized_vector || bits_attribute_present {
+            errors.push(
+                syn::Error::new_spanned(
+                    context.field,
+                    "byte_length applies to vectors sized by #[With(size(N))] or #[FromField(..)]",
+                )
+                .to_compile_error(),
+            );
+            return None;
+        }
+    }
+
+    if bitflags.is_none() && unknown_bits.is_some() {
+        errors.push(
+            syn::Error::new_spanned(
+                context.field,
+                "unknown_bits on a field requires #[bebytes(bitflags(..))]",
+            )
+            .to_compile_error(),
+        );
+        return None;
+    }
+    // A bitflags field is encoded as its integer type, which may be packed with #[bits(N)]
+    if bitflags.is_some()
+        && (size.is_some() && !bits_attribute_present
+            || vec_size_ident.is_some()
+            || size_expression.is_some()
+            || until_marker.is_some()
+            || after_marker.is_some()
+            || tag_from.is_some())
+    {
+        errors.push(
+            syn::Error::new_spanned(
+                context.field,
+                "bitflags can only be combined with #[bits(N)]",
+            )
+            .to_compile_error(),
+        );
+        return None;
+    }
+
+    if word_order.is_some() {
+        let has_other_attributes = endian.is_some()
+            || byte_width.is_some()
+            || bits_attribute_present
+            || size_expression.is_some()
+            || until_marker.is_some()
+            || after_marker.is_some()
+            || tag_from.is_some()
+            || bitflags.is_some();
+        if !handle_word_order(context, has_other_attributes, errors) {
+            return None;
+        }
+    }
+
+    if let Some(width) = byte_width {
+        let has_other_attributes = bits_attribute_present
+            || size.is_some()
+            || vec_size_ident.is_some()
+            || size_expression.is_some()
+            || until_marker.is_some()
+            || after_marker.is_some()
+            || tag_from.is_some()
+            || bitflags.is_some();
+        return handle_byte_width(context, width, has_other_attributes, errors);
+    }
+
+    if let Some(tag_path) = tag_from {
+        return handle_tag_from(
+            context,
+            tag_path,
+            bits_attribute_present
+                || size.is_some()
+                || vec_size_ident.is_some()
+                || size_expression.is_some()
+                || until_marker.is_some()
+                || after_marker.is_some(),
+            errors,
+        );
+    }
 
     // Check for marker attributes
     if let Some(marker) = until_marker {
@@ -229,7 +527,7 @@ fn determine_field_type(
             syn::Type::Path(tp) if !tp.path.segments.is_empty() => {
                 let segment = &tp.path.segments[0];
                 match &segment.ident {
-                    ident if ident == "Vec" || ident == "String" => {
+                    ident if ident == "String" || utils::is_vec_of_u8(tp) => {
                         return Some(FieldType::SizeExpression(expr));
                     }
                     _ => {
@@ -262,16 +560,24 @@ fn determine_field_type(
             }) = &arr.len
             {
                 if let Ok(length) = len.base10_parse() {
-                    return Some(FieldType::Array(length));
+                    return Some(FieldType::Array(Some(length)));
                 }
+                return None;
             }
-            None
+            // Non-literal lengths such as const generic parameters are only
+            // known at monomorphization time
+            Some(FieldType::Array(None))
         }
+        syn::Type::Path(tp) if utils::is_phantom_data(tp) => Some(FieldType::PhantomData),
         syn::Type::Path(tp) if !tp.path.segments.is_empty() => {
             let segment = &tp.path.segments[0];
             match &segment.ident {
-                ident if ident == "Vec" => Some(FieldType::Vector(size, vec_size_ident)),
-                ident if ident == "Option" => Some(FieldType::OptionType),
+                ident if ident == "Vec" => {
+                    Some(FieldType::Vector(size, vec_size_ident, byte_length))
+                }
+                ident if ident == "Option" => {
+                    Some(FieldType::OptionType(size, vec_size_ident, option_mode))
+                }
                 ident if ident == "String" => Some(FieldType::String(size, vec_size_ident)),
                 ident if !utils::is_primitive_identity(ident) => Some(FieldType::CustomType),
                 _ => None,
@@ -281,16 +587,32 @@ fn determine_field_type(
     }
 }
 
+#[allow(clippy::too_many_lines)]
 pub fn handle_struct(context: &mut StructContext) {
-    // First validate byte completeness
-    if let Err(validation_error) = crate::bit_validation::validate_byte_completeness(context.fields)
-    {
-        context.errors.push(validation_error);
-        return;
-    }
+    // A C layout pads bit fields to whole storage units, other structs must fill whole bytes
+    let c_layout = if context.c_layout {
+        match crate::bit_validation::c_layout(context.fields) {
+            Ok(layout) => Some(layout),
+            Err(e) => {
+                context.errors.push(e.to_compile_error());
+                return;
+            }
+        }
+    } else {
+        if let Err(validation_error) =
+            crate::bit_validation::validate_byte_completeness(context.fields)
+        {
+            context.errors.push(validation_error);
+            return;
+        }
+        None
+    };
 
     // Initialize ProcessingContext for functional approach
     let processing_ctx = crate::functional::ProcessingContext::new(context.endianness);
+    let bit_endianness = context.bit_order.unwrap_or(context.endianness);
+    let placements = crate::bit_validation::bit_placements(context.fields).unwrap_or_default();
+    let bits_struct_type: syn::Type = syn::parse_quote!(u128);
 
     // Use FieldDataBuilder for functional accumulation
     let mut builder = crate::functional::FieldDataBuilder::new();
@@ -298,35 +620,132 @@ pub fn handle_struct(context: &mut StructContext) {
 
     // Track current bit position for auto-calculation
     let mut current_bit_position = 0;
+    // Offset of `_bit_sum` from the start of the current group of positioned bit fields
+    let mut group_cursor = 0;
+
+    if let Some(layout) = &c_layout {
+        // Padding is written through `to_*_bytes`, like bit fields
+        *context.has_bit_fields = true;
+        builder = builder.add_result(c_layout_size_check(layout.size));
+    }
 
-    for (idx, field) in context.fields.named.iter().enumerate() {
-        let is_last = idx == context.fields.named.len() - 1;
+    for (idx, field) in context.fields.iter().enumerate() {
+        let is_last = idx == context.fields.len() - 1;
 
+        let field_name = utils::field_binding(idx, field);
+        let mut field_access = match context.field_access {
+            FieldAccess::SelfMember => {
+                let field_member = utils::field_member(idx, field);
+                quote! { self.#field_member }
+            }
+            FieldAccess::Binding => quote! { *#field_name },
+        };
+        let attr_data = attrs::parse_attributes_with_expressions(&field.attrs, &mut errors);
+        let bitflags = attr_data.bitflags.clone().map(|bits_type| {
+            (
+                bits_type,
+                attr_data.unknown_bits.unwrap_or(UnknownBits::Keep),
+            )
+        });
+        if bitflags.is_some() {
+            field_access = quote! { ::bebytes::bitflags::Flags::bits(&#field_access) };
+        }
+        // A bits_struct field joins the bit stream as the integer of its packed bits
+        let is_bits_struct = attr_data.is_bits_attribute
+            && bitflags.is_none()
+            && matches!(&field.ty, syn::Type::Path(tp) if is_bits_struct_path(tp));
+        let bits_size = attr_data.size.unwrap_or_default();
+        if is_bits_struct {
+            let to_bits = bits_struct_conversion(bit_endianness, "to");
+            field_access = quote! { ::bebytes::BitsStruct::#to_bits(&#field_access) };
+        }
         let field_context = FieldContext {
             field,
-            field_name: field.ident.clone().unwrap(),
-            field_type: &field.ty,
+            field_name,
+            field_access,
+            field_type: match &bitflags {
+                Some((bits_type, _)) => bits_type,
+                None if is_bits_struct => &bits_struct_type,
+                None => &field.ty,
+            },
             is_last_field: is_last,
         };
 
-        // Create a new processing context for this field
-        let field_processing_ctx = processing_ctx
-            .clone()
-            .with_bit_position(current_bit_position)
-            .with_last_field(is_last);
+        if let Some(padding) = c_layout.as_ref().map(|layout| layout.padding[idx]) {
+            if padding > 0 {
+                current_bit_position += padding;
+                builder = builder.add_result(c_layout_padding(padding));
+            }
+        }
+
+        // Positioned bit fields are placed inside their group instead of after the previous field
+        let placement = placements.get(idx).and_then(Option::as_ref);
+        let mut field_bit_position = current_bit_position
+            + placement.map_or(0, |placement| placement.stream_offset(bit_endianness));
+
+        // Create a new processing context for this field, bit fields use the struct's bit order
+        // and `#[bebytes(endian = ..)]` or `word_order` fixes the byte order of other fields
+        let word_order = attr_data.word_order;
+        let field_processing_ctx = if attr_data.is_bits_attribute {
+            crate::functional::ProcessingContext::new(bit_endianness)
+        } else if let Some(endian) = attr_data.endian {
+            crate::functional::ProcessingContext::new(endian)
+        } else if let Some(word_order) = word_order {
+            crate::functional::ProcessingContext::new(word_order.endianness())
+        } else {
+            processing_ctx.clone()
+        }
+        .with_bit_position(field_bit_position)
+        .with_last_field(is_last);
 
         if let Some(field_type) = determine_field_type(
             &field_context,
-            &field.attrs,
+            attr_data,
             &mut errors,
             context.has_bit_fields,
         ) {
-            let result = process_field_type(
+            let mut result = process_field_type(
                 &field_context,
                 field_type,
                 &field_processing_ctx,
-                &mut current_bit_position,
+                &mut field_bit_position,
             );
+            match placement {
+                Some(placement) => {
+                    result = result.map(|field_result| {
+                        place_bit_field(field_result, placement, bit_endianness, &mut group_cursor)
+                    });
+                    if placement.last_in_group {
+                        current_bit_position += placement.group_width;
+                    }
+                }
+                None => current_bit_position = field_bit_position,
+            }
+            if let Some((_, unknown_bits)) = &bitflags {
+                result = result.map(|field_result| {
+                    wrap_bitflags_field(&field_context, &field.ty, *unknown_bits, field_result)
+                });
+            }
+            if word_order.is_some_and(crate::word_order::WordOrder::swaps_word_bytes) {
+                result = result.map(|field_result| {
+                    crate::word_order::wrap_word_order_field(
+                        &field_context.field_name,
+                        &field.ty,
+                        field_result,
+                    )
+                });
+            }
+            if is_bits_struct {
+                result = result.map(|field_result| {
+                    wrap_bits_struct_field(
+                        &field_context,
+                        &field.ty,
+                        bits_size,
+                        bit_endianness,
+                        field_result,
+                    )
+                });
+            }
 
             match result {
                 Ok(field_result) => {
@@ -337,6 +756,11 @@ pub fn handle_struct(context: &mut StructContext) {
         }
     }
 
+    if let Some(layout) = c_layout.filter(|layout| layout.tail_padding > 0) {
+        current_bit_position += layout.tail_padding;
+        builder = builder.add_result(c_layout_padding(layout.tail_padding));
+    }
+
     // Build the final FieldData
     let mut field_data = builder.build();
     field_data.errors = errors;
@@ -353,7 +777,211 @@ pub fn handle_struct(context: &mut StructContext) {
     context.named_fields.extend(field_data.named_fields);
 }
 
+/// Moves `_bit_sum` to a positioned bit field before it is read or written, and to the end
+/// of its group after the last field. The group's bits are counted once, at its first field
+fn place_bit_field(
+    mut result: crate::functional::FieldProcessResult,
+    placement: &crate::bit_validation::BitPlacement,
+    endianness: crate::consts::Endianness,
+    group_cursor: &mut usize,
+) -> crate::functional::FieldProcessResult {
+    let move_cursor = |from: usize, to: usize| match to.cmp(&from) {
+        core::cmp::Ordering::Greater => {
+            let delta = to - from;
+            quote! { _bit_sum += #delta; }
+        }
+        core::cmp::Ordering::Less => {
+            let delta = from - to;
+            quote! { _bit_sum -= #delta; }
+        }
+        core::cmp::Ordering::Equal => quote! {},
+    };
+
+    let mut prefix = quote! {};
+    let mut parsing_check = quote! {};
+    if placement.first_in_group {
+        *group_cursor = 0;
+        let group_bytes = placement.group_width / 8;
+        // Fields are read out of order, so the whole group is checked up front
+        parsing_check = quote! {
+            if bytes.len() < _bit_sum / 8 + #group_bytes {
+                return Err(::bebytes::BeBytesError::InsufficientData {
+                    expected: #group_bytes,
+                    actual: bytes.len().saturating_sub(_bit_sum / 8),
+                });
+            }
+        };
+    }
+    let offset = placement.stream_offset(endianness);
+    prefix.extend(move_cursor(*group_cursor, offset));
+    *group_cursor = offset + placement.range.len();
+    let (suffix, writing_suffix) = if placement.last_in_group {
+        let to_end = move_cursor(*group_cursor, placement.group_width);
+        // Reserved bytes that no field touched still belong to the group
+        let padding = quote! {
+            #to_end
+            if bytes.len() < _bit_sum / 8 {
+                bytes.resize(_bit_sum / 8, 0);
+            }
+        };
+        (to_end, padding)
+    } else {
+        (quote! {}, quote! {})
+    };
+
+    let parsing = result.parsing;
+    result.parsing = quote! {
+        #parsing_check
+        #prefix
+        #parsing
+        #suffix
+    };
+    let writing = result.writing;
+    result.writing = quote! {
+        #prefix
+        #writing
+        #writing_suffix
+    };
+    result.direct_writing = convert_to_direct_writing(&result.writing);
+    result.bit_sum = if placement.first_in_group {
+        crate::functional::pure_helpers::create_bit_sum(placement.group_width)
+    } else {
+        quote! {}
+    };
+    result
+}
+
+/// Checks up front that the whole of a `c_layout` struct is there, padding included
+fn c_layout_size_check(size: usize) -> crate::functional::FieldProcessResult {
+    crate::functional::FieldProcessResult::new(
+        quote! {},
+        quote! {
+            if bytes.len() < #size {
+                return Err(::bebytes::BeBytesError::InsufficientData {
+                    expected: #size,
+                    actual: bytes.len(),
+                });
+            }
+        },
+        quote! {},
+        quote! {},
+        quote! {},
+        quote! {},
+    )
+}
+
+/// Skips padding bits of a `c_layout` struct, which are written as zero and ignored on read
+fn c_layout_padding(padding: usize) -> crate::functional::FieldProcessResult {
+    let writing = quote! {
+        _bit_sum += #padding;
+        if bytes.len() < _bit_sum / 8 {
+            bytes.resize(_bit_sum / 8, 0);
+        }
+    };
+    crate::functional::FieldProcessResult::new(
+        quote! {},
+        quote! { _bit_sum += #padding; },
+        writing.clone(),
+        convert_to_direct_writing(&writing),
+        quote! {},
+        crate::functional::pure_helpers::create_bit_sum(padding),
+    )
+}
+
+/// Converts the integer encoding of a `#[bebytes(bitflags(..))]` field to and from the
+/// flags type. Writing already reads the integer through the field access.
+fn wrap_bitflags_field(
+    context: &FieldContext,
+    flags_type: &syn::Type,
+    unknown_bits: UnknownBits,
+    mut result: crate::functional::FieldProcessResult,
+) -> crate::functional::FieldProcessResult {
+    let field_name = &context.field_name;
+    let flags_trait = quote! { <#flags_type as ::bebytes::bitflags::Flags> };
+    let from_bits = match unknown_bits {
+        UnknownBits::Keep => quote! { #flags_trait::from_bits_retain(#field_name) },
+        UnknownBits::Mask => quote! { #flags_trait::from_bits_truncate(#field_name) },
+        UnknownBits::Reject => quote! {
+            #flags_trait::from_bits(#field_name).ok_or(
+                ::bebytes::BeBytesError::InvalidDiscriminantLarge {
+                    value: #field_name as u128,
+                    type_name: stringify!(#flags_type),
+                },
+            )?
+        },
+    };
+    let parsing = result.parsing;
+    result.parsing = quote! {
+        #parsing
+        let #field_name = #from_bits;
+    };
+    // Bit field range checks in `new()` compare the integer value
+    if !result.limit_check.is_empty() {
+        let limit_check = result.limit_check;
+        result.limit_check = quote! {
+            {
+                let #field_name = ::bebytes::bitflags::Flags::bits(&#field_name);
+                #limit_check
+            }
+        };
+    }
+    result
+}
+
+/// Whether a `#[bits(N)]` field of this type is a `#[bebytes(bits_struct)]` rather than an
+/// unsupported type such as a float, `isize`, `String` or `Vec`
+fn is_bits_struct_path(tp: &syn::TypePath) -> bool {
+    !utils::is_primitive_type(tp)
+        && tp.path.segments.last().is_some_and(|segment| {
+            !matches!(
+                segment.ident.to_string().as_str(),
+                "isize" | "usize" | "String" | "Vec" | "Option" | "Box"
+            )
+        })
+}
+
+/// Name of the `BitsStruct` method that packs (`"to"`) or unpacks (`"from"`) a
+/// `bits_struct` in the bit order of its parent
+fn bits_struct_conversion(endianness: crate::consts::Endianness, direction: &str) -> syn::Ident {
+    let order = match endianness {
+        crate::consts::Endianness::Big => "msb0",
+        crate::consts::Endianness::Little => "lsb0",
+    };
+    quote::format_ident!("{direction}_{order}_bits")
+}
+
+/// Converts the packed bits of a `#[bebytes(bits_struct)]` field back to the struct on read,
+/// and checks at compile time that `#[bits(N)]` matches the width of the struct
+fn wrap_bits_struct_field(
+    context: &FieldContext,
+    bits_type: &syn::Type,
+    size: usize,
+    endianness: crate::consts::Endianness,
+    mut result: crate::functional::FieldProcessResult,
+) -> crate::functional::FieldProcessResult {
+    let field_name = &context.field_name;
+    let width_check = quote! { let () = ::bebytes::BitsStructWidth::<#bits_type, #size>::CHECK; };
+    let from_bits = bits_struct_conversion(endianness, "from");
+
+    let parsing = result.parsing;
+    result.parsing = quote! {
+        #width_check
+        #parsing
+        let #field_name = <#bits_type as ::bebytes::BitsStruct>::#from_bits(#field_name);
+    };
+    let writing = result.writing;
+    result.writing = quote! {
+        #width_check
+        #writing
+    };
+    result.direct_writing = convert_to_direct_writing(&result.writing);
+    // The packed bits always fit, the struct's own constructor checks its fields
+    result.limit_check = quote! {};
+    result
+}
+
 // New functional field processor
+#[allow(clippy::too_many_lines)]
 fn process_field_type(
     context: &FieldContext,
     field_type: FieldType,
@@ -371,6 +999,17 @@ fn process_field_type(
             *current_bit_position += size;
             Ok(result)
         }
+        FieldType::BitsArray(size, length) => {
+            let result = process_bits_array_functional(
+                context,
+                size,
+                length,
+                processing_ctx,
+                *current_bit_position,
+            )?;
+            *current_bit_position += size * length;
+            Ok(result)
+        }
         FieldType::PrimitiveType => {
             let result = process_primitive_type_functional(context, processing_ctx)?;
             // Update bit position based on primitive size
@@ -378,26 +1017,56 @@ fn process_field_type(
             *current_bit_position += field_size * 8;
             Ok(result)
         }
+        FieldType::NarrowInteger(width, int_type) => {
+            let result =
+                process_narrow_integer_functional(context, width, int_type, processing_ctx);
+            *current_bit_position += width * 8;
+            Ok(result)
+        }
         FieldType::Array(length) => {
             let result = process_array_functional(context, length, processing_ctx)?;
-            // Arrays are always byte arrays in our case
-            *current_bit_position += length * 8;
+            // Arrays are whole bytes, so a size that is only known after
+            // monomorphization still keeps byte alignment
+            *current_bit_position +=
+                utils::get_fixed_type_size(context.field_type).unwrap_or(0) * 8;
             Ok(result)
         }
-        FieldType::Vector(size, vec_size_ident) => {
-            let result = process_vector_functional(context, size, vec_size_ident, processing_ctx)?;
+        FieldType::Vector(size, vec_size_ident, byte_length) => {
+            let result = process_vector_functional(
+                context,
+                size,
+                vec_size_ident,
+                byte_length,
+                processing_ctx,
+            )?;
             // Vectors have variable size, but we need to track something for bit field positioning
             if let Some(s) = size {
-                *current_bit_position += s * 8;
+                let element_size = if byte_length {
+                    1
+                } else {
+                    vector_element_size(context.field_type)
+                };
+                *current_bit_position += s * element_size * 8;
             }
             Ok(result)
         }
-        FieldType::OptionType => {
-            let result = process_option_type_functional(context, processing_ctx)?;
+        FieldType::OptionType(size, size_ident, mode) => {
+            let result =
+                process_option_type_functional(context, size, size_ident, &mode, processing_ctx)?;
+            let tag_size = match &mode {
+                OptionMode::Tag(tag_type) => tag_type.byte_size(),
+                OptionMode::NoneValue(_) => 0,
+                // An absent value takes no space, so the position is no longer known
+                OptionMode::PresentIf(_) => return Ok(result),
+            };
             if let syn::Type::Path(tp) = context.field_type {
                 if let Some(inner_type) = utils::solve_for_inner_type(tp, "Option") {
                     let inner_size = match &inner_type {
-                        syn::Type::Path(_) => utils::get_primitive_type_size(&inner_type)?,
+                        syn::Type::Path(inner_tp) if utils::is_primitive_type(inner_tp) => {
+                            utils::get_primitive_type_size(&inner_type)?
+                        }
+                        // Structs, enums, vectors and strings have no size known here
+                        syn::Type::Path(_) => return Ok(result),
                         syn::Type::Array(arr) => {
                             if let syn::Expr::Lit(syn::ExprLit {
                                 lit: syn::Lit::Int(lit_int),
@@ -419,7 +1088,7 @@ fn process_field_type(
                             ));
                         }
                     };
-                    *current_bit_position += (inner_size + 1) * 8;
+                    *current_bit_position += (inner_size + tag_size) * 8;
                 }
             }
             Ok(result)
@@ -444,6 +1113,15 @@ fn process_field_type(
             // This is OK because bit fields can't come after custom types anyway
             Ok(process_custom_type_functional(context, processing_ctx))
         }
+        FieldType::PhantomData => Ok(process_phantom_data_functional(context)),
+        FieldType::TaggedBody(tag_path) => {
+            // The body size depends on the variant, like other custom types
+            Ok(process_tagged_body_functional(
+                context,
+                &tag_path,
+                processing_ctx,
+            ))
+        }
         FieldType::UntilMarker(marker) => {
             // UntilMarker fields have dynamic size
             Ok(process_until_marker_functional(
@@ -483,10 +1161,21 @@ fn process_bits_field_functional(
     let field_name = &context.field_name;
     let field_type = context.field_type;
 
-    let accessor = create_field_accessor(field_name, AccessorMode::Copy);
+    let accessor = create_field_accessor(field_name, &context.field_access, AccessorMode::Copy);
     let bit_sum = crate::functional::pure_helpers::create_bit_sum(size);
-    let limit_check =
-        crate::functional::pure_helpers::create_bit_field_limit_check(field_name, field_type, size);
+
+    // A bool flag is read as a `u8` bit and always fits, writing already casts it to `u8`
+    let is_bool = matches!(field_type, syn::Type::Path(tp) if tp.path.is_ident("bool"));
+    let limit_check = if is_bool {
+        quote! {}
+    } else {
+        crate::functional::pure_helpers::create_bit_field_limit_check(field_name, field_type, size)
+    };
+    let parsing_type: syn::Type = if is_bool {
+        syn::parse_quote!(u8)
+    } else {
+        field_type.clone()
+    };
 
     // Get the size of the underlying number type
     let number_length = utils::get_primitive_type_size(field_type)
@@ -501,7 +1190,7 @@ fn process_bits_field_functional(
         false
     };
 
-    let (parsing, writing) = if number_length > 1 || size > 8 {
+    let (mut parsing, writing) = if number_length > 1 || size > 8 {
         let ctx = MultiByteBitFieldCtx {
             field_name,
             field_type,
@@ -516,7 +1205,7 @@ fn process_bits_field_functional(
     } else {
         generate_single_byte_bit_field(
             field_name,
-            field_type,
+            &parsing_type,
             size,
             mask,
             bit_position,
@@ -524,6 +1213,24 @@ fn process_bits_field_functional(
         )
     };
 
+    if is_bool {
+        parsing = quote! {
+            #parsing
+            let #field_name = #field_name != 0;
+        };
+    }
+
+    // Bits are read as a non-negative value, so signed fields copy their top bit upwards
+    if crate::functional::pure_helpers::signed_bit_field_type(field_type).is_some() {
+        let shift = 8 * number_length - size;
+        if shift > 0 {
+            parsing = quote! {
+                #parsing
+                let #field_name = (#field_name << #shift) >> #shift;
+            };
+        }
+    }
+
     let direct_writing = convert_to_direct_writing(&writing);
     Ok(crate::functional::FieldProcessResult::new(
         limit_check,
@@ -535,6 +1242,86 @@ fn process_bits_field_functional(
     ))
 }
 
+// Packs each element of a `#[bits(N)]` array as its own bit field, in index order
+fn process_bits_array_functional(
+    context: &FieldContext,
+    size: usize,
+    length: usize,
+    processing_ctx: &crate::functional::ProcessingContext,
+    bit_position: usize,
+) -> Result<crate::functional::FieldProcessResult, syn::Error> {
+    let field_name = &context.field_name;
+    let syn::Type::Array(arr) = context.field_type else {
+        return Err(syn::Error::new_spanned(
+            context.field_type,
+            "Expected an array type",
+        ));
+    };
+    let element_type = &*arr.elem;
+
+    let mut limit_checks = Vec::new();
+    let mut parsings = Vec::new();
+    let mut writings = Vec::new();
+    let mut elements = Vec::new();
+    for index in 0..length {
+        let element = quote::format_ident!("__{}_{}", field_name, index);
+        let element_context = FieldContext {
+            field: context.field,
+            field_name: element.clone(),
+            field_access: quote! { #field_name[#index] },
+            field_type: element_type,
+            is_last_field: false,
+        };
+        let result = process_bits_field_functional(
+            &element_context,
+            size,
+            processing_ctx,
+            bit_position + index * size,
+        )?;
+
+        // Out-of-range elements are reported with their index, before the element is written
+        let label = format!("{field_name}[{index}]");
+        let element_check = crate::functional::pure_helpers::create_bit_array_element_check(
+            &quote! { #element },
+            element_type,
+            &label,
+            size,
+        );
+        limit_checks.push(
+            crate::functional::pure_helpers::create_bit_array_element_check(
+                &quote! { #field_name[#index] },
+                element_type,
+                &label,
+                size,
+            ),
+        );
+        parsings.push(result.parsing);
+        let accessor = result.accessor;
+        let writing = result.writing;
+        writings.push(quote! {
+            #accessor
+            #element_check
+            #writing
+        });
+        elements.push(element);
+    }
+
+    let parsing = quote! {
+        #(#parsings)*
+        let #field_name = [#(#elements),*];
+    };
+    let writing = quote! { #(#writings)* };
+    let direct_writing = convert_to_direct_writing(&writing);
+    Ok(crate::functional::FieldProcessResult::new(
+        quote! { #(#limit_checks)* },
+        parsing,
+        writing,
+        direct_writing,
+        create_field_accessor(field_name, &context.field_access, AccessorMode::Copy),
+        crate::functional::pure_helpers::create_bit_sum(size * length),
+    ))
+}
+
 // Context for multi-byte bit field generation
 struct MultiByteBitFieldCtx<'a> {
     field_name: &'a syn::Ident,
@@ -746,6 +1533,18 @@ fn generate_value_preparation(
             }
             let value = #field_name as u32 & #mask as u32;
         }
+    } else if let Some(int_type) =
+        crate::functional::pure_helpers::signed_bit_field_type(field_type)
+    {
+        let range_check = crate::functional::pure_helpers::create_signed_bit_field_check(
+            field_name,
+            int_type,
+            mask.count_ones() as usize,
+        );
+        quote! {
+            #range_check
+            let value = #field_name & #mask as #field_type;
+        }
     } else {
         quote! {
             if #field_name > #mask as #field_type {
@@ -770,7 +1569,7 @@ fn process_primitive_type_functional(
 
     let field_size = utils::get_primitive_type_size(field_type)?;
 
-    let accessor = create_field_accessor(field_name, AccessorMode::Copy);
+    let accessor = create_field_accessor(field_name, &context.field_access, AccessorMode::Copy);
     let bit_sum = crate::functional::pure_helpers::create_byte_bit_sum(field_size);
 
     let parsing_tokens = vec![
@@ -805,11 +1604,86 @@ fn process_primitive_type_functional(
     ))
 }
 
+/// Integer stored in its `width` least significant bytes. Signed values are sign-extended
+/// when decoded
+fn process_narrow_integer_functional(
+    context: &FieldContext,
+    width: usize,
+    int_type: crate::enums::FlagType,
+    processing_ctx: &crate::functional::ProcessingContext,
+) -> crate::functional::FieldProcessResult {
+    let field_name = &context.field_name;
+    let field_type = context.field_type;
+    let type_size = int_type.byte_size();
+
+    let accessor = create_field_accessor(field_name, &context.field_access, AccessorMode::Copy);
+    let bit_sum = crate::functional::pure_helpers::create_byte_bit_sum(width);
+    let range_check = crate::functional::pure_helpers::create_byte_width_check(
+        &quote! { #field_name },
+        &field_name.to_string(),
+        int_type,
+        width,
+    );
+
+    // The kept bytes sit at the end of the big-endian representation and at the
+    // start of the little-endian one
+    let (kept_bytes, from_bytes_method, to_bytes_method) = match processing_ctx.endianness {
+        crate::consts::Endianness::Big => (
+            {
+                let start = type_size - width;
+                quote! { #start.. }
+            },
+            quote! { from_be_bytes },
+            quote! { to_be_bytes },
+        ),
+        crate::consts::Endianness::Little => (
+            quote! { ..#width },
+            quote! { from_le_bytes },
+            quote! { to_le_bytes },
+        ),
+    };
+    let sign_extension = if int_type.is_signed() && width < type_size {
+        let shift = 8 * (type_size - width);
+        quote! { let #field_name = (#field_name << #shift) >> #shift; }
+    } else {
+        quote! {}
+    };
+
+    let byte_indices = crate::functional::pure_helpers::create_byte_indices(width);
+    let parsing = quote! {
+        #byte_indices
+        let mut value_bytes = [0u8; #type_size];
+        value_bytes[#kept_bytes].copy_from_slice(&bytes[byte_index..end_byte_index]);
+        let #field_name = #field_type::#from_bytes_method(value_bytes);
+        #sign_extension
+    };
+
+    let writing = quote! {
+        #range_check
+        bytes.extend_from_slice(&#field_name.#to_bytes_method()[#kept_bytes]);
+        _bit_sum += #width * 8;
+    };
+
+    let direct_writing = quote! {
+        #range_check
+        buf.put_slice(&#field_name.#to_bytes_method()[#kept_bytes]);
+    };
+
+    crate::functional::FieldProcessResult::new(
+        range_check,
+        parsing,
+        writing,
+        direct_writing,
+        accessor,
+        bit_sum,
+    )
+}
+
 // Functional version of handle_array
 fn process_array_functional(
     context: &FieldContext,
-    length: usize,
-    _processing_ctx: &crate::functional::ProcessingContext,
+    length: Option<usize>,
+    processing_ctx: &crate::functional::ProcessingContext,
 ) -> Result<crate::functional::FieldProcessResult, syn::Error> {
     let field_name = &context.field_name;
     let field_type = context.field_type;
@@ -818,8 +1692,17 @@ fn process_array_functional(
         if let syn::Type::Path(elem) = &*tp.elem {
             let segments = &elem.path.segments;
             if segments.len() == 1 && segments[0].ident == "u8" {
-                let accessor = create_field_accessor(field_name, AccessorMode::Copy);
-                let bit_sum = crate::functional::pure_helpers::create_byte_bit_sum(length);
+                let accessor =
+                    create_field_accessor(field_name, &context.field_access, AccessorMode::Copy);
+                let (length, bit_sum) = if let Some(length) = length {
+                    (
+                        quote! { #length },
+                        crate::functional::pure_helpers::create_byte_bit_sum(length),
+                    )
+                } else {
+                    let length = &tp.len;
+                    (quote! { #length }, quote! { bit_sum += (#length) * 8; })
+                };
 
                 let parsing = quote! {
                     byte_index = _bit_sum / 8;
@@ -848,12 +1731,193 @@ fn process_array_functional(
                 ));
             }
         }
+
+        // Other element types are encoded one by one. Elements may not be `Copy`,
+        // so the array is encoded through a reference
+        let accessor =
+            create_field_accessor(field_name, &context.field_access, AccessorMode::Reference);
+        let endianness = processing_ctx.endianness;
+        let array_parsing = generate_array_parsing(tp, endianness)?;
+        let parsing = quote! {
+            let #field_name = #array_parsing;
+        };
+        let writing = generate_element_writing(field_name, field_type, endianness, false)?;
+        let direct_writing = generate_element_writing(field_name, field_type, endianness, true)?;
+        let size = generate_element_size(field_type)?;
+
+        return Ok(crate::functional::FieldProcessResult::new(
+            quote! {},
+            parsing,
+            writing,
+            direct_writing,
+            accessor,
+            quote! { bit_sum += 8 * (#size); },
+        ));
+    }
+
+    Err(syn::Error::new_spanned(
+        field_type,
+        "Unsupported array type",
+    ))
+}
+
+/// Expression decoding an array element by element from `bytes` at `_bit_sum`
+fn generate_array_parsing(
+    array_type: &syn::TypeArray,
+    endianness: crate::consts::Endianness,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let element_type = &*array_type.elem;
+    let length = &array_type.len;
+    let element = quote::format_ident!("__element");
+    let element_parsing = generate_element_parsing(&element, element_type, endianness)?;
+
+    // Primitives are `Copy` and `Default`, so they can fill the array in place
+    if matches!(element_type, syn::Type::Path(tp) if utils::is_primitive_type(tp)) {
+        return Ok(quote! {
+            {
+                let mut array = [<#element_type as ::core::default::Default>::default(); #length];
+                for slot in array.iter_mut() {
+                    #element_parsing
+                    *slot = #element;
+                }
+                array
+            }
+        });
+    }
+
+    Ok(quote! {
+        {
+            let mut elements = ::bebytes::Vec::with_capacity(#length);
+            for _ in 0..#length {
+                #element_parsing
+                elements.push(#element);
+            }
+            match <[#element_type; #length] as ::core::convert::TryFrom<::bebytes::Vec<#element_type>>>::try_from(elements) {
+                Ok(array) => array,
+                Err(_) => unreachable!("exactly {} elements were decoded", #length),
+            }
+        }
+    })
+}
+
+/// Statements decoding one array element into `element`
+fn generate_element_parsing(
+    element: &syn::Ident,
+    element_type: &syn::Type,
+    endianness: crate::consts::Endianness,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    match element_type {
+        syn::Type::Array(array_type) => {
+            let array_parsing = generate_array_parsing(array_type, endianness)?;
+            Ok(quote! { let #element = #array_parsing; })
+        }
+        syn::Type::Path(tp) if utils::is_primitive_type(tp) => {
+            let element_size = utils::get_primitive_type_size(element_type)?;
+            let element_indices =
+                crate::functional::pure_helpers::create_byte_indices(element_size);
+            let element_parsing = crate::functional::pure_helpers::create_primitive_parsing(
+                element,
+                element_type,
+                endianness,
+            )?;
+            Ok(quote! {
+                #element_indices
+                #element_parsing
+            })
+        }
+        syn::Type::Path(_) => {
+            let try_from_bytes_method = utils::get_try_from_bytes_method(endianness);
+            Ok(quote_spanned! { element_type.span() =>
+                byte_index = _bit_sum / 8;
+                let (#element, bytes_read) = <#element_type as ::bebytes::BeBytes>::#try_from_bytes_method(&bytes[byte_index..])?;
+                _bit_sum += bytes_read * 8;
+            })
+        }
+        _ => Err(syn::Error::new_spanned(
+            element_type,
+            "Unsupported array element type",
+        )),
+    }
+}
+
+/// Statements encoding `element`, a reference to an array or to one of its elements.
+/// `direct` selects writing to `buf` instead of `bytes`
+fn generate_element_writing(
+    element: &syn::Ident,
+    element_type: &syn::Type,
+    endianness: crate::consts::Endianness,
+    direct: bool,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    match element_type {
+        syn::Type::Array(array_type) => {
+            let inner = quote::format_ident!("__element");
+            let inner_writing =
+                generate_element_writing(&inner, &array_type.elem, endianness, direct)?;
+            Ok(quote! {
+                for #inner in #element.iter() {
+                    #inner_writing
+                }
+            })
+        }
+        syn::Type::Path(tp) if utils::is_primitive_type(tp) => {
+            let primitive_writing = if direct {
+                crate::functional::pure_helpers::create_primitive_direct_writing(
+                    element,
+                    element_type,
+                    endianness,
+                )?
+            } else {
+                crate::functional::pure_helpers::create_primitive_writing(
+                    element,
+                    element_type,
+                    endianness,
+                )?
+            };
+            Ok(quote! {
+                let #element = *#element;
+                #primitive_writing
+            })
+        }
+        syn::Type::Path(_) if direct => {
+            let encode_method = utils::get_encode_to_method(endianness);
+            Ok(quote_spanned! { element_type.span() =>
+                let _ = ::bebytes::BeBytes::#encode_method(#element, buf);
+            })
+        }
+        syn::Type::Path(_) => {
+            let to_bytes_method = utils::get_to_bytes_method(endianness);
+            Ok(quote_spanned! { element_type.span() =>
+                let bytes_data = &::bebytes::BeBytes::#to_bytes_method(#element);
+                bytes.reserve(bytes_data.len());
+                bytes.extend_from_slice(bytes_data);
+                _bit_sum += bytes_data.len() * 8;
+            })
+        }
+        _ => Err(syn::Error::new_spanned(
+            element_type,
+            "Unsupported array element type",
+        )),
+    }
+}
+
+/// Expression for the encoded size of an array or of one of its elements
+fn generate_element_size(element_type: &syn::Type) -> Result<proc_macro2::TokenStream, syn::Error> {
+    match element_type {
+        syn::Type::Array(array_type) => {
+            let length = &array_type.len;
+            let element_size = generate_element_size(&array_type.elem)?;
+            Ok(quote! { (#length) * (#element_size) })
+        }
+        syn::Type::Path(tp) if utils::is_primitive_type(tp) => {
+            let size = utils::get_primitive_type_size(element_type)?;
+            Ok(quote! { #size })
+        }
+        syn::Type::Path(_) => Ok(quote! { <#element_type as ::bebytes::BeBytes>::field_size() }),
+        _ => Err(syn::Error::new_spanned(
+            element_type,
+            "Unsupported array element type",
+        )),
     }
-
-    Err(syn::Error::new_spanned(
-        field_type,
-        "Unsupported array type",
-    ))
 }
 
 // Helper to generate vector parsing and writing tokens for primitive types
@@ -933,6 +1997,137 @@ fn generate_primitive_vector_tokens(
     }
 }
 
+/// Size in bytes of the elements of a `Vec` of primitives, or 1 for other vectors
+fn vector_element_size(field_type: &syn::Type) -> usize {
+    if let syn::Type::Path(tp) = field_type {
+        if let Some(inner_type) = utils::solve_for_inner_type(tp, "Vec") {
+            return utils::get_primitive_type_size(&inner_type).unwrap_or(1);
+        }
+    }
+    1
+}
+
+// Helper to generate vector tokens for primitives wider than a byte. Each element is
+// read and written with the struct's endianness, and sizes count elements unless
+// `byte_length` is set
+#[allow(clippy::too_many_arguments)]
+fn generate_element_vector_tokens(
+    field_name: &syn::Ident,
+    element_type: &syn::Type,
+    size: Option<usize>,
+    vec_size_ident: Option<Vec<syn::Ident>>,
+    byte_length: bool,
+    is_last_field: bool,
+    field: &syn::Field,
+    endianness: crate::consts::Endianness,
+) -> Result<
+    (
+        proc_macro2::TokenStream,
+        proc_macro2::TokenStream,
+        proc_macro2::TokenStream,
+        proc_macro2::TokenStream,
+    ),
+    syn::Error,
+> {
+    let element_size = utils::get_primitive_type_size(element_type)?;
+    let element = quote::format_ident!("__element");
+    let element_indices = crate::functional::pure_helpers::create_byte_indices(element_size);
+    let element_parsing = crate::functional::pure_helpers::create_primitive_parsing(
+        &element,
+        element_type,
+        endianness,
+    )?;
+    let element_writing = crate::functional::pure_helpers::create_primitive_writing(
+        &element,
+        element_type,
+        endianness,
+    )?;
+    let element_direct_writing = crate::functional::pure_helpers::create_primitive_direct_writing(
+        &element,
+        element_type,
+        endianness,
+    )?;
+
+    let length = match (size, vec_size_ident) {
+        (_, Some(ident_path)) => {
+            let field_access_parse =
+                crate::functional::pure_helpers::generate_field_access_path(&ident_path);
+            Some(quote! { #field_access_parse as usize })
+        }
+        (Some(s), None) => Some(quote! { #s }),
+        (None, None) => {
+            if !is_last_field {
+                return Err(syn::Error::new(
+                    field.ty.span(),
+                    "Unbounded vectors can only be used as padding at the end of a struct",
+                ));
+            }
+            None
+        }
+    };
+
+    let bit_sum = match size {
+        Some(s) if byte_length => crate::functional::pure_helpers::create_byte_bit_sum(s),
+        Some(s) => crate::functional::pure_helpers::create_byte_bit_sum(s * element_size),
+        None => quote! { bit_sum = 4096 * 8; },
+    };
+
+    let parsing = if let Some(length) = length {
+        let element_count = if byte_length {
+            quote! {
+                let byte_length = #length;
+                if byte_length % #element_size != 0 {
+                    // The last element would be cut short
+                    return Err(::bebytes::BeBytesError::InvalidByteLength {
+                        length: byte_length,
+                        element_size: #element_size,
+                        field: stringify!(#field_name),
+                    });
+                }
+                let vec_size = byte_length / #element_size;
+            }
+        } else {
+            quote! { let vec_size = #length; }
+        };
+        quote! {
+            #element_count
+            // The length comes from the input, so only reserve what the buffer can hold
+            let mut #field_name = ::bebytes::Vec::with_capacity(
+                vec_size.min(bytes.len().saturating_sub(_bit_sum / 8) / #element_size),
+            );
+            for _ in 0..vec_size {
+                #element_indices
+                #element_parsing
+                #field_name.push(#element);
+            }
+        }
+    } else {
+        quote! {
+            let mut #field_name = ::bebytes::Vec::new();
+            while _bit_sum / 8 < bytes.len() {
+                #element_indices
+                #element_parsing
+                #field_name.push(#element);
+            }
+        }
+    };
+
+    let writing = quote! {
+        bytes.reserve(#field_name.len() * #element_size);
+        for &#element in #field_name.iter() {
+            #element_writing
+        }
+    };
+
+    let direct_writing = quote! {
+        for &#element in #field_name.iter() {
+            #element_direct_writing
+        }
+    };
+
+    Ok((bit_sum, parsing, writing, direct_writing))
+}
+
 // Helper to generate parsing code for custom type vectors
 fn generate_custom_vector_parsing(
     field_name: &syn::Ident,
@@ -947,7 +2142,7 @@ fn generate_custom_vector_parsing(
         Ok(quote! {
             let mut bytes_consumed = 0;
             while _bit_sum / 8 + bytes_consumed < bytes.len() {
-                match #inner_type_name::#try_from_bytes_method(&bytes[_bit_sum / 8 + bytes_consumed..]) {
+                match <#inner_type_name as ::bebytes::BeBytes>::#try_from_bytes_method(&bytes[_bit_sum / 8 + bytes_consumed..]) {
                     Ok((item, consumed)) => {
                         #field_name.push(item);
                         bytes_consumed += consumed;
@@ -964,7 +2159,7 @@ fn generate_custom_vector_parsing(
                 if _bit_sum / 8 + bytes_consumed >= bytes.len() {
                     break;
                 }
-                match #inner_type_name::#try_from_bytes_method(&bytes[_bit_sum / 8 + bytes_consumed..]) {
+                match <#inner_type_name as ::bebytes::BeBytes>::#try_from_bytes_method(&bytes[_bit_sum / 8 + bytes_consumed..]) {
                     Ok((item, consumed)) => {
                         #field_name.push(item);
                         bytes_consumed += consumed;
@@ -984,7 +2179,7 @@ fn generate_custom_vector_parsing(
                 if _bit_sum / 8 + bytes_consumed >= bytes.len() {
                     break;
                 }
-                match #inner_type_name::#try_from_bytes_method(&bytes[_bit_sum / 8 + bytes_consumed..]) {
+                match <#inner_type_name as ::bebytes::BeBytes>::#try_from_bytes_method(&bytes[_bit_sum / 8 + bytes_consumed..]) {
                     Ok((item, consumed)) => {
                         #field_name.push(item);
                         bytes_consumed += consumed;
@@ -1000,10 +2195,12 @@ fn generate_custom_vector_parsing(
 }
 
 // Functional version of handle_vector
+#[allow(clippy::too_many_lines)]
 fn process_vector_functional(
     context: &FieldContext,
     size: Option<usize>,
     vec_size_ident: Option<Vec<syn::Ident>>,
+    byte_length: bool,
     processing_ctx: &crate::functional::ProcessingContext,
 ) -> Result<crate::functional::FieldProcessResult, syn::Error> {
     let field_name = &context.field_name;
@@ -1011,10 +2208,32 @@ fn process_vector_functional(
     let field = context.field;
     let is_last_field = context.is_last_field;
 
-    let accessor = create_field_accessor(field_name, AccessorMode::Clone);
+    let accessor = create_field_accessor(field_name, &context.field_access, AccessorMode::Clone);
 
     if let syn::Type::Path(tp) = field_type {
         if let Some(syn::Type::Path(ref inner_tp)) = utils::solve_for_inner_type(tp, "Vec") {
+            if utils::is_primitive_type(inner_tp) && !inner_tp.path.is_ident("u8") {
+                let (bit_sum, parsing, writing, direct_writing) = generate_element_vector_tokens(
+                    field_name,
+                    &syn::Type::Path(inner_tp.clone()),
+                    size,
+                    vec_size_ident,
+                    byte_length,
+                    is_last_field,
+                    field,
+                    processing_ctx.endianness,
+                )?;
+
+                return Ok(crate::functional::FieldProcessResult::new(
+                    quote! {},
+                    parsing,
+                    writing,
+                    direct_writing,
+                    accessor,
+                    bit_sum,
+                ));
+            }
+
             if utils::is_primitive_type(inner_tp) {
                 let (bit_sum, parsing, writing) = generate_primitive_vector_tokens(
                     field_name,
@@ -1038,9 +2257,18 @@ fn process_vector_functional(
                 ));
             }
 
-            // Handle vector of custom types
-            let inner_type_path = &inner_tp.path;
-            let inner_type_name = quote! { #inner_type_path };
+            if byte_length {
+                return Err(syn::Error::new(
+                    field.ty.span(),
+                    "byte_length is only supported for vectors of primitive types",
+                ));
+            }
+
+            // Handle vector of custom types. Elements are encoded through a
+            // reference so the element type does not need to be `Clone`.
+            let accessor =
+                create_field_accessor(field_name, &context.field_access, AccessorMode::Reference);
+            let inner_type_name = quote! { #inner_tp };
 
             let try_from_bytes_method = utils::get_try_from_bytes_method(processing_ctx.endianness);
             let to_bytes_method = utils::get_to_bytes_method(processing_ctx.endianness);
@@ -1066,12 +2294,12 @@ fn process_vector_functional(
 
             let writing = quote! {
                 let total_size = #field_name.iter().map(|item| {
-                    BeBytes::#to_bytes_method(item).len()
+                    ::bebytes::BeBytes::#to_bytes_method(item).len()
                 }).sum::<usize>();
                 bytes.reserve(total_size);
 
-                for item in &#field_name {
-                    let item_bytes = BeBytes::#to_bytes_method(item);
+                for item in #field_name.iter() {
+                    let item_bytes = ::bebytes::BeBytes::#to_bytes_method(item);
                     bytes.extend_from_slice(&item_bytes);
                     _bit_sum += item_bytes.len() * 8;
                 }
@@ -1079,8 +2307,8 @@ fn process_vector_functional(
 
             let encode_method = utils::get_encode_to_method(processing_ctx.endianness);
             let direct_writing = quote! {
-                for item in &#field_name {
-                    let _ = BeBytes::#encode_method(item, buf);
+                for item in #field_name.iter() {
+                    let _ = ::bebytes::BeBytes::#encode_method(item, buf);
                 }
             };
             return Ok(crate::functional::FieldProcessResult::new(
@@ -1237,9 +2465,10 @@ fn create_option_inner_writing(
 }
 
 fn process_option_array(
-    field_name: &syn::Ident,
+    context: &FieldContext,
     arr: &syn::TypeArray,
 ) -> Result<crate::functional::FieldProcessResult, syn::Error> {
+    let field_name = &context.field_name;
     if let syn::Type::Path(elem) = &*arr.elem {
         if elem.path.is_ident("u8") {
             if let syn::Expr::Lit(syn::ExprLit {
@@ -1250,7 +2479,8 @@ fn process_option_array(
                 let array_len: usize = lit_int.base10_parse()?;
                 let total_size = array_len + 1;
 
-                let accessor = create_field_accessor(field_name, AccessorMode::Copy);
+                let accessor =
+                    create_field_accessor(field_name, &context.field_access, AccessorMode::Copy);
                 let bit_sum = crate::functional::pure_helpers::create_byte_bit_sum(total_size);
 
                 let parsing = quote! {
@@ -1324,8 +2554,317 @@ fn process_option_array(
     ))
 }
 
+/// `Option` encoded with the writers of its inner type, wrapped according to the field's
+/// `OptionMode`. A tagged `None` of a fixed-size value is padded with zeros; structs, enums,
+/// vectors and strings have no fixed size, so their tagged `None` is just the tag
+#[allow(clippy::too_many_lines)]
+fn process_option_value_functional(
+    context: &FieldContext,
+    inner_type: &syn::Type,
+    size: Option<usize>,
+    size_ident: Option<Vec<syn::Ident>>,
+    mode: &OptionMode,
+    processing_ctx: &crate::functional::ProcessingContext,
+) -> Result<crate::functional::FieldProcessResult, syn::Error> {
+    let field_name = &context.field_name;
+    let endianness = processing_ctx.endianness;
+    let inner_context = FieldContext {
+        field: context.field,
+        field_name: field_name.clone(),
+        field_access: context.field_access.clone(),
+        field_type: inner_type,
+        is_last_field: context.is_last_field,
+    };
+
+    if let OptionMode::NoneValue(_) = mode {
+        let is_integer = matches!(inner_type, syn::Type::Path(inner_tp)
+        if inner_tp.path.get_ident().is_some_and(|ident| {
+            crate::enums::FlagType::from_int_ident(ident).is_some()
+        }));
+        if !is_integer {
+            return Err(syn::Error::new_spanned(
+                context.field_type,
+                "none_value applies to Options of integer types",
+            ));
+        }
+    }
+
+    // Primitives and byte arrays are written by value, other types through a reference
+    let is_sized = size.is_some() || size_ident.is_some();
+    let (inner_result, fixed_size, by_value) = match inner_type {
+        syn::Type::Path(inner_tp) if utils::is_primitive_type(inner_tp) => {
+            let inner_size = utils::get_primitive_type_size(inner_type)?;
+            (
+                process_primitive_type_functional(&inner_context, processing_ctx)?,
+                Some(quote! { #inner_size }),
+                true,
+            )
+        }
+        syn::Type::Path(inner_tp) if utils::solve_for_inner_type(inner_tp, "Vec").is_some() => (
+            process_vector_functional(&inner_context, size, size_ident, false, processing_ctx)?,
+            None,
+            false,
+        ),
+        syn::Type::Path(inner_tp) if inner_tp.path.is_ident("String") => (
+            process_string_functional(&inner_context, size, size_ident, processing_ctx)?,
+            None,
+            false,
+        ),
+        // Padded to `field_size()`, so a `None` takes the place of the value like primitives
+        syn::Type::Path(_) if !is_sized => (
+            process_custom_type_functional(&inner_context, processing_ctx),
+            Some(quote! { <#inner_type as ::bebytes::BeBytes>::field_size() }),
+            false,
+        ),
+        syn::Type::Array(arr) if !is_sized => {
+            let length = match &arr.len {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Int(len),
+                    ..
+                }) => Some(len.base10_parse()?),
+                _ => None,
+            };
+            let is_byte_array =
+                matches!(&*arr.elem, syn::Type::Path(elem) if elem.path.is_ident("u8"));
+            (
+                process_array_functional(&inner_context, length, processing_ctx)?,
+                Some(generate_element_size(inner_type)?),
+                is_byte_array,
+            )
+        }
+        syn::Type::Path(_) | syn::Type::Array(_) => {
+            return Err(syn::Error::new_spanned(
+                context.field_type,
+                "Size attributes on an Option apply to Option<Vec<..>> and Option<String>",
+            ))
+        }
+        _ => {
+            return Err(syn::Error::new_spanned(
+                inner_type,
+                "Unsupported Option inner type",
+            ))
+        }
+    };
+
+    let accessor_mode = if let OptionMode::NoneValue(_) = mode {
+        AccessorMode::Copy
+    } else {
+        AccessorMode::Reference
+    };
+    let accessor = create_field_accessor(field_name, &context.field_access, accessor_mode);
+    let unpack = if by_value {
+        quote! { let #field_name = *#field_name; }
+    } else {
+        quote! {}
+    };
+    let inner_bit_sum = inner_result.bit_sum;
+    let inner_parsing = inner_result.parsing;
+    let inner_writing = inner_result.writing;
+    let inner_direct_writing = inner_result.direct_writing;
+
+    let (bit_sum, parsing, writing, direct_writing) = match mode {
+        OptionMode::Tag(tag_type) => {
+            let tag_size = tag_type.byte_size();
+            let tag_read = tag_type.read_tokens(endianness);
+            let to_bytes_method = utils::get_to_bytes_method(endianness);
+            let none_tag = tag_type.literal(0);
+            let some_tag = tag_type.literal(1);
+            let unknown_tag = if *tag_type == crate::enums::FlagType::U8 {
+                quote! {
+                    ::bebytes::BeBytesError::InvalidDiscriminant {
+                        value: tag,
+                        type_name: "Option",
+                    }
+                }
+            } else {
+                quote! {
+                    ::bebytes::BeBytesError::InvalidDiscriminantLarge {
+                        value: u128::from(tag),
+                        type_name: "Option",
+                    }
+                }
+            };
+            let (skip_padding, write_padding, direct_padding) =
+                if let Some(fixed_size) = &fixed_size {
+                    (
+                        quote! {
+                            end_byte_index = _bit_sum / 8 + #fixed_size;
+                            if end_byte_index > bytes.len() {
+                                return Err(::bebytes::BeBytesError::InsufficientData {
+                                    expected: end_byte_index,
+                                    actual: bytes.len(),
+                                });
+                            }
+                            _bit_sum += 8 * #fixed_size;
+                        },
+                        quote! {
+                            for _ in 0..#fixed_size {
+                                ::bebytes::BufMut::put_u8(bytes, 0);
+                            }
+                            _bit_sum += 8 * #fixed_size;
+                        },
+                        quote! {
+                            for _ in 0..#fixed_size {
+                                buf.put_u8(0);
+                            }
+                        },
+                    )
+                } else {
+                    (quote! {}, quote! {}, quote! {})
+                };
+
+            let bit_sum = quote! {
+                bit_sum += 8 * #tag_size;
+                #inner_bit_sum
+            };
+            let parsing = quote! {
+                byte_index = _bit_sum / 8;
+                end_byte_index = byte_index + #tag_size;
+                if end_byte_index > bytes.len() {
+                    return Err(::bebytes::BeBytesError::InsufficientData {
+                        expected: end_byte_index,
+                        actual: bytes.len(),
+                    });
+                }
+                let tag = {
+                    let bytes = &bytes[byte_index..];
+                    #tag_read
+                };
+                _bit_sum += 8 * #tag_size;
+                let #field_name = match tag {
+                    #none_tag => {
+                        #skip_padding
+                        None
+                    }
+                    #some_tag => {
+                        #inner_parsing
+                        Some(#field_name)
+                    }
+                    tag => return Err(#unknown_tag),
+                };
+            };
+            let writing = quote! {
+                match #field_name {
+                    None => {
+                        ::bebytes::BufMut::put_slice(bytes, &#none_tag.#to_bytes_method());
+                        _bit_sum += 8 * #tag_size;
+                        #write_padding
+                    }
+                    Some(#field_name) => {
+                        ::bebytes::BufMut::put_slice(bytes, &#some_tag.#to_bytes_method());
+                        _bit_sum += 8 * #tag_size;
+                        #unpack
+                        #inner_writing
+                    }
+                }
+            };
+            let direct_writing = quote! {
+                match #field_name {
+                    None => {
+                        buf.put_slice(&#none_tag.#to_bytes_method());
+                        #direct_padding
+                    }
+                    Some(#field_name) => {
+                        buf.put_slice(&#some_tag.#to_bytes_method());
+                        #unpack
+                        #inner_direct_writing
+                    }
+                }
+            };
+            (bit_sum, parsing, writing, direct_writing)
+        }
+        OptionMode::PresentIf(presence_path) => {
+            let presence =
+                crate::functional::pure_helpers::generate_field_access_path(presence_path);
+            let parsing = quote! {
+                let #field_name = if (#presence as u128) != 0 {
+                    #inner_parsing
+                    Some(#field_name)
+                } else {
+                    None
+                };
+            };
+            // Encoding panics on a mismatch, which could not be decoded back
+            let check_presence = quote! {
+                let present = (#presence as u128) != 0;
+                if present != #field_name.is_some() {
+                    panic!(
+                        "Presence field {} is {} but {} is {}",
+                        stringify!(#presence),
+                        present,
+                        stringify!(#field_name),
+                        if present { "None" } else { "Some" }
+                    );
+                }
+            };
+            let writing = quote! {
+                #check_presence
+                if let Some(#field_name) = #field_name {
+                    #unpack
+                    #inner_writing
+                }
+            };
+            let direct_writing = quote! {
+                #check_presence
+                if let Some(#field_name) = #field_name {
+                    #unpack
+                    #inner_direct_writing
+                }
+            };
+            (inner_bit_sum, parsing, writing, direct_writing)
+        }
+        OptionMode::NoneValue(none_value) => {
+            let none_value = quote! { let none_value: #inner_type = #none_value; };
+            let parsing = quote! {
+                #inner_parsing
+                #none_value
+                let #field_name = if #field_name == none_value {
+                    None
+                } else {
+                    Some(#field_name)
+                };
+            };
+            // `Some(none_value)` would decode as `None`, so encoding it panics
+            let unwrap_value = quote! {
+                #none_value
+                let #field_name = match #field_name {
+                    Some(value) if value == none_value => panic!(
+                        "{} holds its none_value {}",
+                        stringify!(#field_name),
+                        value
+                    ),
+                    Some(value) => value,
+                    None => none_value,
+                };
+            };
+            let writing = quote! {
+                #unwrap_value
+                #inner_writing
+            };
+            let direct_writing = quote! {
+                #unwrap_value
+                #inner_direct_writing
+            };
+            (inner_bit_sum, parsing, writing, direct_writing)
+        }
+    };
+
+    Ok(crate::functional::FieldProcessResult::new(
+        quote! {},
+        parsing,
+        writing,
+        direct_writing,
+        accessor,
+        bit_sum,
+    ))
+}
+
+#[allow(clippy::too_many_lines)]
 fn process_option_type_functional(
     context: &FieldContext,
+    size: Option<usize>,
+    size_ident: Option<Vec<syn::Ident>>,
+    mode: &OptionMode,
     processing_ctx: &crate::functional::ProcessingContext,
 ) -> Result<crate::functional::FieldProcessResult, syn::Error> {
     let field_name = &context.field_name;
@@ -1333,12 +2872,31 @@ fn process_option_type_functional(
 
     if let syn::Type::Path(tp) = field_type {
         if let Some(inner_type) = utils::solve_for_inner_type(tp, "Option") {
+            let is_fixed_size = match &inner_type {
+                syn::Type::Path(inner_tp) => utils::is_primitive_type(inner_tp),
+                syn::Type::Array(_) => true,
+                _ => false,
+            };
+            if is_fixed_size && (size.is_some() || size_ident.is_some()) {
+                return Err(syn::Error::new_spanned(
+                    field_type,
+                    "Size attributes on an Option apply to Option<Vec<..>> and Option<String>",
+                ));
+            }
+
+            // The default 1-byte tag has dedicated encoders for fixed-size values
+            let is_default_tag = matches!(mode, OptionMode::Tag(crate::enums::FlagType::U8));
+
             if let syn::Type::Path(inner_tp) = &inner_type {
-                if utils::is_primitive_type(inner_tp) {
+                if is_default_tag && utils::is_primitive_type(inner_tp) {
                     let field_size = utils::get_primitive_type_size(&inner_type)?;
                     let total_size = field_size + 1;
 
-                    let accessor = create_field_accessor(field_name, AccessorMode::Copy);
+                    let accessor = create_field_accessor(
+                        field_name,
+                        &context.field_access,
+                        AccessorMode::Copy,
+                    );
                     let bit_sum = crate::functional::pure_helpers::create_byte_bit_sum(total_size);
 
                     let value_parsing = create_option_inner_parsing(
@@ -1409,8 +2967,19 @@ fn process_option_type_functional(
             }
 
             if let syn::Type::Array(arr) = &inner_type {
-                return process_option_array(field_name, arr);
+                if is_default_tag {
+                    return process_option_array(context, arr);
+                }
             }
+
+            return process_option_value_functional(
+                context,
+                &inner_type,
+                size,
+                size_ident,
+                mode,
+                processing_ctx,
+            );
         }
     }
 
@@ -1420,6 +2989,68 @@ fn process_option_type_functional(
     ))
 }
 
+// Data-carrying enum whose variant is selected by an earlier field instead of a leading tag
+fn process_tagged_body_functional(
+    context: &FieldContext,
+    tag_path: &[syn::Ident],
+    processing_ctx: &crate::functional::ProcessingContext,
+) -> crate::functional::FieldProcessResult {
+    let field_name = &context.field_name;
+    let field_type = context.field_type;
+    let tag_value = crate::functional::pure_helpers::generate_field_access_path(tag_path);
+
+    let accessor =
+        create_field_accessor(field_name, &context.field_access, AccessorMode::Reference);
+
+    let bit_sum = quote! {
+        bit_sum += 8 * <#field_type as ::bebytes::BeBytes>::field_size();
+    };
+
+    let (try_from_body_method, to_body_method) = match processing_ctx.endianness {
+        crate::consts::Endianness::Big => (quote! { try_from_be_body }, quote! { to_be_body }),
+        crate::consts::Endianness::Little => (quote! { try_from_le_body }, quote! { to_le_body }),
+    };
+
+    let parsing = quote_spanned! { context.field.span() =>
+        byte_index = _bit_sum / 8;
+        let predicted_size = <#field_type as ::bebytes::BeBytes>::field_size();
+        end_byte_index = usize::min(bytes.len(), byte_index + predicted_size);
+        let (#field_name, bytes_read) = <#field_type as ::bebytes::TaggedBody>::#try_from_body_method(
+            #tag_value as u128,
+            &bytes[byte_index..end_byte_index],
+        )?;
+        _bit_sum += bytes_read * 8;
+    };
+
+    let writing = quote_spanned! { context.field.span() =>
+        let variant_tag = ::bebytes::TaggedBody::tag(#field_name);
+        if #tag_value as u128 != variant_tag {
+            panic!(
+                "Tag field {} is {} but {} holds a variant with tag {}",
+                stringify!(#tag_value),
+                #tag_value as u128,
+                stringify!(#field_name),
+                variant_tag
+            );
+        }
+        let bytes_data = &::bebytes::TaggedBody::#to_body_method(#field_name);
+        bytes.reserve(bytes_data.len());
+        bytes.extend_from_slice(bytes_data);
+        _bit_sum += bytes_data.len() * 8;
+    };
+
+    let direct_writing = convert_to_direct_writing(&writing);
+
+    crate::functional::FieldProcessResult::new(
+        quote! {},
+        parsing,
+        writing,
+        direct_writing,
+        accessor,
+        bit_sum,
+    )
+}
+
 // Functional version of handle_custom_type
 fn process_custom_type_functional(
     context: &FieldContext,
@@ -1428,10 +3059,11 @@ fn process_custom_type_functional(
     let field_name = &context.field_name;
     let field_type = context.field_type;
 
-    let accessor = create_field_accessor(field_name, AccessorMode::Clone);
+    let accessor =
+        create_field_accessor(field_name, &context.field_access, AccessorMode::Reference);
 
     let bit_sum = quote! {
-        bit_sum += 8 * #field_type::field_size();
+        bit_sum += 8 * <#field_type as ::bebytes::BeBytes>::field_size();
     };
 
     let try_from_bytes_method = utils::get_try_from_bytes_method(processing_ctx.endianness);
@@ -1440,21 +3072,21 @@ fn process_custom_type_functional(
 
     let parsing = quote_spanned! { context.field.span() =>
         byte_index = _bit_sum / 8;
-        let predicted_size = #field_type::field_size();
+        let predicted_size = <#field_type as ::bebytes::BeBytes>::field_size();
         end_byte_index = usize::min(bytes.len(), byte_index + predicted_size);
-        let (#field_name, bytes_read) = #field_type::#try_from_bytes_method(&bytes[byte_index..end_byte_index])?;
+        let (#field_name, bytes_read) = <#field_type as ::bebytes::BeBytes>::#try_from_bytes_method(&bytes[byte_index..end_byte_index])?;
         _bit_sum += bytes_read * 8;
     };
 
     let writing = quote_spanned! { context.field.span() =>
-        let bytes_data = &BeBytes::#to_bytes_method(&#field_name);
+        let bytes_data = &::bebytes::BeBytes::#to_bytes_method(#field_name);
         bytes.reserve(bytes_data.len());
         bytes.extend_from_slice(bytes_data);
         _bit_sum += bytes_data.len() * 8;
     };
 
     let direct_writing = quote_spanned! { context.field.span() =>
-        let _ = BeBytes::#encode_method(&#field_name, buf);
+        let _ = ::bebytes::BeBytes::#encode_method(#field_name, buf);
     };
     crate::functional::FieldProcessResult::new(
         quote! {},
@@ -1466,6 +3098,23 @@ fn process_custom_type_functional(
     )
 }
 
+// PhantomData takes no bytes and is recreated when parsing
+fn process_phantom_data_functional(
+    context: &FieldContext,
+) -> crate::functional::FieldProcessResult {
+    let field_name = &context.field_name;
+    let accessor =
+        create_field_accessor(field_name, &context.field_access, AccessorMode::Reference);
+    crate::functional::FieldProcessResult::new(
+        quote! {},
+        quote! { let #field_name = ::core::marker::PhantomData; },
+        quote! { let _ = #field_name; },
+        quote! { let _ = #field_name; },
+        accessor,
+        quote! {},
+    )
+}
+
 // Functional version for String fields
 fn process_string_functional(
     context: &FieldContext,
@@ -1477,7 +3126,8 @@ fn process_string_functional(
     let field = context.field;
     let is_last_field = context.is_last_field;
 
-    let accessor = create_field_accessor(field_name, AccessorMode::Reference);
+    let accessor =
+        create_field_accessor(field_name, &context.field_access, AccessorMode::Reference);
 
     // Generate parsing code based on size constraints
     let (bit_sum, parsing, writing) = match (size, string_size_ident) {
@@ -1520,7 +3170,7 @@ fn process_size_expression_functional(
     let field_name = &context.field_name;
     let field_type = context.field_type;
 
-    let accessor = create_field_accessor(field_name, AccessorMode::Clone);
+    let accessor = create_field_accessor(field_name, &context.field_access, AccessorMode::Clone);
 
     // Generate the size calculation code
     let size_calculation = size_expr.generate_evaluation_code();
@@ -1767,7 +3417,8 @@ fn process_until_marker_functional(
     // For UntilMarker, Vec<u8> is the only supported type for now
     // Since UntilMarker reads bytes until the marker
 
-    let accessor = quote! { let #field_name = &self.#field_name; };
+    let field_access = &context.field_access;
+    let accessor = quote! { let #field_name = &#field_access; };
 
     // Bit sum is dynamic for marker fields
     let bit_sum = quote! {};
@@ -1850,7 +3501,8 @@ fn process_after_marker_functional(
 ) -> crate::functional::FieldProcessResult {
     let field_name = &context.field_name;
 
-    let accessor = quote! { let #field_name = &self.#field_name; };
+    let field_access = &context.field_access;
+    let accessor = quote! { let #field_name = &#field_access; };
 
     // Bit sum is dynamic for marker fields
     let bit_sum = quote! {};
@@ -1907,7 +3559,8 @@ fn process_vec_of_vecs_with_marker_functional(
 ) -> crate::functional::FieldProcessResult {
     let field_name = &context.field_name;
 
-    let accessor = quote! { let #field_name = &self.#field_name; };
+    let field_access = &context.field_access;
+    let accessor = quote! { let #field_name = &#field_access; };
 
     // Bit sum is dynamic for marker fields
     let bit_sum = quote! {};
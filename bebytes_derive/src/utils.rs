@@ -1,5 +1,5 @@
 use quote::quote;
-use syn::AngleBracketedGenericArguments;
+use syn::{spanned::Spanned, AngleBracketedGenericArguments};
 
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
@@ -59,6 +59,29 @@ pub fn get_primitive_type_size(field_type: &syn::Type) -> Result<usize, syn::Err
     }
 }
 
+/// Get the encoded size of a primitive, or of an array of primitives (possibly nested)
+/// with a literal length. Other types are only sized after monomorphization.
+pub fn get_fixed_type_size(field_type: &syn::Type) -> Option<usize> {
+    match field_type {
+        syn::Type::Array(array) => {
+            let length = array_literal_length(array)?;
+            get_fixed_type_size(&array.elem)?.checked_mul(length)
+        }
+        _ => get_primitive_type_size(field_type).ok(),
+    }
+}
+
+/// Length of an array type written as an integer literal, `None` for const generics
+pub fn array_literal_length(array: &syn::TypeArray) -> Option<usize> {
+    match &array.len {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(len),
+            ..
+        }) => len.base10_parse().ok(),
+        _ => None,
+    }
+}
+
 /// Get the maximum number of bits that can be stored in a primitive type
 pub fn get_primitive_type_max_bits(field_type: &syn::Type) -> Result<usize, syn::Error> {
     match field_type {
@@ -68,6 +91,7 @@ pub fn get_primitive_type_max_bits(field_type: &syn::Type) -> Result<usize, syn:
         syn::Type::Path(tp) if tp.path.is_ident("i64") || tp.path.is_ident("u64") => Ok(64),
         syn::Type::Path(tp) if tp.path.is_ident("i128") || tp.path.is_ident("u128") => Ok(128),
         syn::Type::Path(tp) if tp.path.is_ident("char") => Ok(32),
+        syn::Type::Path(tp) if tp.path.is_ident("bool") => Ok(1),
         _ => Err(syn::Error::new_spanned(
             field_type,
             "Unsupported type for bits attribute",
@@ -96,6 +120,60 @@ pub fn solve_for_inner_type(input: &syn::TypePath, identifier: &str) -> Option<s
     Some(inner_type.clone())
 }
 
+/// Name of the local binding that holds a field's value in generated code.
+/// Positional fields are bound as `field_0`, `field_1`, ...
+pub fn field_binding(index: usize, field: &syn::Field) -> syn::Ident {
+    match &field.ident {
+        Some(ident) => ident.clone(),
+        None => quote::format_ident!("field_{}", index, span = field.ty.span()),
+    }
+}
+
+/// Member used to access a field through `self` (`self.name` or `self.0`)
+pub fn field_member(index: usize, field: &syn::Field) -> syn::Member {
+    match &field.ident {
+        Some(ident) => syn::Member::Named(ident.clone()),
+        None => syn::Member::Unnamed(syn::Index {
+            index: u32::try_from(index).unwrap_or(u32::MAX),
+            span: field.ty.span(),
+        }),
+    }
+}
+
+/// Add a `BeBytes` bound to every type parameter so that fields of generic
+/// type can be parsed and written through the trait
+pub fn add_be_bytes_bounds(generics: &syn::Generics) -> syn::Generics {
+    let mut generics = generics.clone();
+    let type_params = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+    let where_clause = generics.make_where_clause();
+    for ident in type_params {
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#ident: ::bebytes::BeBytes));
+    }
+    generics
+}
+
+/// Build `Path { a, b }`, `Path(field_0, field_1)` or `Path` from the field bindings.
+/// The same tokens serve as a pattern that binds every field by its binding name.
+pub fn fields_constructor(
+    path: &proc_macro2::TokenStream,
+    fields: &syn::Fields,
+) -> proc_macro2::TokenStream {
+    let bindings = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| field_binding(index, field));
+    match fields {
+        syn::Fields::Named(_) => quote! { #path { #( #bindings, )* } },
+        syn::Fields::Unnamed(_) => quote! { #path( #( #bindings, )* ) },
+        syn::Fields::Unit => quote! { #path },
+    }
+}
+
 pub fn is_primitive_identity(ident: &syn::Ident) -> bool {
     PRIMITIVES.iter().any(|&primitive| ident == primitive)
 }
@@ -106,6 +184,22 @@ pub fn is_primitive_type(tp: &syn::TypePath) -> bool {
         .any(|&primitive| tp.path.is_ident(primitive))
 }
 
+/// Check if a type is `PhantomData<..>`, under any path
+pub fn is_phantom_data(tp: &syn::TypePath) -> bool {
+    tp.path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "PhantomData")
+}
+
+/// Check if a type is Vec<u8>
+pub fn is_vec_of_u8(tp: &syn::TypePath) -> bool {
+    matches!(
+        solve_for_inner_type(tp, "Vec"),
+        Some(syn::Type::Path(inner_tp)) if inner_tp.path.is_ident("u8")
+    )
+}
+
 /// Check if a type is Vec<Vec<u8>>
 pub fn is_vec_of_vec_u8(tp: &syn::TypePath) -> bool {
     // Check if outer type is Vec
@@ -255,6 +349,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_get_fixed_type_size() {
+        let cases: [(syn::Type, Option<usize>); 6] = [
+            (parse_quote!(u32), Some(4)),
+            (parse_quote!([u8; 6]), Some(6)),
+            (parse_quote!([u16; 4]), Some(8)),
+            (parse_quote!([[i64; 2]; 3]), Some(48)),
+            (parse_quote!([Point; 4]), None),
+            (parse_quote!([u8; N]), None),
+        ];
+        for (ty, expected) in cases {
+            assert_eq!(get_fixed_type_size(&ty), expected, "{}", quote!(#ty));
+        }
+    }
+
+    #[test]
+    fn test_is_vec_of_u8() {
+        let cases: [(syn::Type, bool); 4] = [
+            (parse_quote!(Vec<u8>), true),
+            (parse_quote!(Vec<u16>), false),
+            (parse_quote!(Vec<Vec<u8>>), false),
+            (parse_quote!(Option<u8>), false),
+        ];
+        for (ty, expected) in cases {
+            if let syn::Type::Path(tp) = &ty {
+                assert_eq!(is_vec_of_u8(tp), expected, "{}", quote!(#ty));
+            }
+        }
+    }
+
     #[test]
     fn test_get_from_bytes_method() {
         use crate::consts::Endianness;
@@ -0,0 +1,119 @@
+//! `#[bebytes(word_order = "..")]`: 32 and 64-bit values split into 16-bit words, as
+//! Modbus registers carry them
+
+use quote::quote;
+
+/// Order of the 16-bit words and their bytes in a `#[bebytes(word_order = "..")]` field,
+/// named after the bytes `A B C D` of a big-endian 32-bit value. 64-bit values follow
+/// the same pattern over four words
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WordOrder {
+    /// `ABCD`: big-endian
+    Abcd,
+    /// `CDAB`: big-endian words, least significant word first
+    Cdab,
+    /// `BADC`: most significant word first, bytes swapped within each word
+    Badc,
+    /// `DCBA`: little-endian
+    Dcba,
+}
+
+impl WordOrder {
+    pub fn from_lit(value: &syn::LitStr) -> Result<Self, syn::Error> {
+        match value.value().as_str() {
+            "ABCD" => Ok(Self::Abcd),
+            "CDAB" => Ok(Self::Cdab),
+            "BADC" => Ok(Self::Badc),
+            "DCBA" => Ok(Self::Dcba),
+            _ => Err(syn::Error::new(
+                value.span(),
+                "Invalid word order. Expected \"ABCD\", \"CDAB\", \"BADC\" or \"DCBA\"",
+            )),
+        }
+    }
+
+    /// Byte order the value is written in, after `swaps_word_bytes`
+    pub fn endianness(self) -> crate::consts::Endianness {
+        match self {
+            Self::Abcd | Self::Badc => crate::consts::Endianness::Big,
+            Self::Cdab | Self::Dcba => crate::consts::Endianness::Little,
+        }
+    }
+
+    /// Whether the bytes of each word are swapped before the value is written in
+    /// `endianness`. Reversing a little-endian image restores the byte order of each
+    /// word, which gives `CDAB`
+    pub fn swaps_word_bytes(self) -> bool {
+        matches!(self, Self::Cdab | Self::Badc)
+    }
+}
+
+/// Whether `word_order` applies to values of this type
+pub fn is_word_type(tp: &syn::TypePath) -> bool {
+    tp.path.get_ident().is_some_and(|ident| {
+        matches!(
+            ident.to_string().as_str(),
+            "u32" | "i32" | "f32" | "u64" | "i64" | "f64"
+        )
+    })
+}
+
+/// `value` with the two bytes of each of its 16-bit words swapped. Swapping twice
+/// restores the value, so the same conversion serves reading and writing
+pub fn swap_word_bytes(
+    value: &proc_macro2::TokenStream,
+    value_type: &syn::Type,
+) -> proc_macro2::TokenStream {
+    quote! {
+        {
+            let mut __word_bytes = #value.to_ne_bytes();
+            for __word in __word_bytes.chunks_exact_mut(2) {
+                __word.swap(0, 1);
+            }
+            <#value_type>::from_ne_bytes(__word_bytes)
+        }
+    }
+}
+
+/// Swaps the bytes within the words of a `CDAB` or `BADC` field, or of each element of a
+/// vector of them, after it is read and before it is written
+pub fn wrap_word_order_field(
+    field_name: &syn::Ident,
+    field_type: &syn::Type,
+    mut result: crate::functional::FieldProcessResult,
+) -> crate::functional::FieldProcessResult {
+    let element_type = match field_type {
+        syn::Type::Path(tp) => crate::utils::solve_for_inner_type(tp, "Vec"),
+        _ => None,
+    };
+    let conversion = if let Some(element_type) = element_type {
+        let element = quote! { __element };
+        let swapped = swap_word_bytes(&element, &element_type);
+        quote! {
+            let #field_name = #field_name
+                .into_iter()
+                .map(|#element| #swapped)
+                .collect::<::bebytes::Vec<#element_type>>();
+        }
+    } else {
+        let swapped = swap_word_bytes(&quote! { #field_name }, field_type);
+        quote! { let #field_name = #swapped; }
+    };
+
+    let parsing = result.parsing;
+    result.parsing = quote! {
+        #parsing
+        #conversion
+    };
+    let writing = result.writing;
+    result.writing = quote! {
+        #conversion
+        #writing
+    };
+    let direct_writing = result.direct_writing;
+    result.direct_writing = quote! {
+        #conversion
+        #direct_writing
+    };
+    result
+}